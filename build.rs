@@ -0,0 +1,58 @@
+//! Generates a test for every part recorded in the answers of a fixture,
+//! included by `tests/examples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=fixtures");
+
+    let mut tests = String::new();
+    for (day, dir) in sorted_entries(Path::new("fixtures")) {
+        let Some(day) = day.strip_prefix("day").and_then(|n| n.parse::<u8>().ok()) else {
+            continue;
+        };
+
+        for (file, path) in sorted_entries(&dir) {
+            let Some(name) = file.strip_suffix(".toml") else {
+                continue;
+            };
+            let contents = fs::read_to_string(&path).unwrap();
+            let answers: toml::Table =
+                toml::from_str(&contents).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+
+            let ident: String = name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            for (key, part) in [("part1", "One"), ("part2", "Two")] {
+                if answers.contains_key(key) {
+                    writeln!(
+                        tests,
+                        "#[test]\nfn day{day:02}_{ident}_{key}() {{\n    check_fixture({day}, {name:?}, Part::{part});\n}}\n"
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out.join("fixtures.rs"), tests).unwrap();
+}
+
+/// The entries of `dir` with their file name, sorted by name.
+fn sorted_entries(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut entries: Vec<(String, PathBuf)> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            (name, path)
+        })
+        .collect();
+    entries.sort();
+    entries
+}
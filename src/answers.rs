@@ -0,0 +1,162 @@
+use crate::error::{Error, Location, Result};
+use crate::input::data_dir;
+use crate::{Answer, Part, RawOptions};
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory the answers are read from.
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+
+/// An answer as written in the answers file: a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Recorded::Number(n) => write!(f, "{n}"),
+            Recorded::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// The known answers of a day, `dayXX.toml` in the answers directory:
+/// ```toml
+/// part1 = 1908462
+/// part2 = "RNLFDJMCT"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    pub part1: Option<Recorded>,
+    pub part2: Option<Recorded>,
+    /// The options the answers were found with, in an `[options]` table:
+    /// the examples of some days use other parameters than the real inputs.
+    #[serde(default)]
+    pub options: RawOptions,
+}
+
+/// The outcome of comparing an answer with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: Recorded },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self> {
+        toml::from_str(contents).map_err(|e| toml_error(contents, e))
+    }
+
+    /// The answers recorded for `day`, none if there is no file for it.
+    pub fn load(day: u8, user: Option<&str>) -> Result<Self> {
+        Answers::load_path(&answers_path(day, user))
+    }
+
+    /// No answers if the file does not exist.
+    pub(crate) fn load_path(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Recorded> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// Trailing whitespace is ignored, so that multi-line answers can be
+    /// written as TOML multi-line strings.
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        match self.get(part) {
+            None => Check::Missing,
+            Some(expected) if expected.to_string().trim_end() == answer.to_string().trim_end() => {
+                Check::Pass
+            }
+            Some(expected) => Check::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// The directory holding the answers: `$AOC_ANSWERS_DIR` if set, then
+/// `answers/` in the current directory, then `answers/` at the root of the
+/// crate.
+pub fn answers_dir() -> PathBuf {
+    data_dir(ANSWERS_DIR_VAR, "answers")
+}
+
+/// `dayXX.toml` in the answers directory, or `<user>/dayXX.toml` for the
+/// answers of another account's inputs.
+pub fn answers_path(day: u8, user: Option<&str>) -> PathBuf {
+    let file = format!("day{day:02}.toml");
+    match user {
+        Some(user) => answers_dir().join(user).join(file),
+        None => answers_dir().join(file),
+    }
+}
+
+/// A TOML error, located in `contents`.
+pub(crate) fn toml_error(contents: &str, e: toml::de::Error) -> Error {
+    let span = e.span().unwrap_or(0..0);
+    let (location, text) = span_location(contents, span.start, span.end);
+    Error::invalid(location, text, e.message())
+}
+
+fn span_location(contents: &str, start: usize, end: usize) -> (Location, &str) {
+    let start = start.min(contents.len());
+    let line_start = contents[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = contents[start..]
+        .find('\n')
+        .map_or(contents.len(), |i| start + i);
+    let line = &contents[line_start..line_end];
+    let text = &contents[start..end.clamp(start, line_end)];
+    let number = contents[..start].matches('\n').count() + 1;
+
+    (Location::of(number, line, text), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1 = 1908462\npart2 = \"RNLFDJMCT\"\n").unwrap();
+        assert_eq!(answers.part1, Some(Recorded::Number(1908462)));
+        assert_eq!(answers.part2, Some(Recorded::Text("RNLFDJMCT".to_string())));
+
+        let error = Answers::parse("part1 = 1\npart3 = 2\n").unwrap_err();
+        assert!(error.to_string().starts_with("line 2, column 1: "));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1 = 24000\npart2 = '''\n#..#\n.##.\n'''\n").unwrap();
+        assert_eq!(answers.check(Part::One, &24000_u32.into()), Check::Pass);
+        assert_eq!(
+            answers.check(Part::One, &45000_u32.into()),
+            Check::Fail {
+                expected: Recorded::Number(24000)
+            }
+        );
+        assert_eq!(answers.check(Part::Two, &"#..#\n.##.".into()), Check::Pass);
+        assert_eq!(
+            Answers::default().check(Part::One, &24000_u32.into()),
+            Check::Missing
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use crate::error::Result;
+use crate::{Day, InputSource, Part, RawOptions};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How many times each step runs. A phase stops early once it has taken
+/// longer than `time_limit`, so that the slow days stay bearable; at least
+/// one sample is always taken.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub time_limit: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 2,
+            iterations: 10,
+            time_limit: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` without samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let (&min, &max) = (samples.first()?, samples.last()?);
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Stats {
+            samples: samples.len(),
+            min,
+            median,
+            max,
+        })
+    }
+
+    /// Change of the median since `baseline`, in percent.
+    pub fn change_since(&self, baseline: &Stats) -> Option<f64> {
+        let old = baseline.median.as_secs_f64();
+        (old > 0.0).then(|| (self.median.as_secs_f64() - old) / old * 100.0)
+    }
+}
+
+/// The timings of one day, parts that were not run are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub parse: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    pub fn part(&self, part: Part) -> Option<&Stats> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Option<Stats> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Timings saved by a previous run, by day number.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u8, DayBench>,
+}
+
+impl Baseline {
+    /// An empty baseline if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(e) => return Err(e.into()),
+        };
+        serde_json::from_str(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+            .into()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// Replaces the timings of the steps that were run in `bench`, keeping
+    /// the others.
+    pub fn update(&mut self, day: u8, bench: &DayBench) {
+        let saved = self.days.entry(day).or_default();
+        saved.parse = bench.parse.or(saved.parse);
+        saved.part1 = bench.part1.or(saved.part1);
+        saved.part2 = bench.part2.or(saved.part2);
+    }
+}
+
+/// Times `f`, see [`BenchConfig`].
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    let started = Instant::now();
+    for _ in 0..config.warmup {
+        black_box(f());
+        if started.elapsed() > config.time_limit {
+            break;
+        }
+    }
+
+    let started = Instant::now();
+    let mut samples = vec![];
+    while samples.is_empty()
+        || (samples.len() < config.iterations && started.elapsed() <= config.time_limit)
+    {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(samples).expect("at least one sample is taken")
+}
+
+/// Times the parsing and the given parts of `day`. A failing step stops the
+/// benchmark of the day.
+pub fn bench_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    options: &RawOptions,
+    config: &BenchConfig,
+) -> Result<DayBench> {
+    let contents = day.read_input(source)?;
+    let input = day.parse_with(&contents, options)?;
+    for part in parts {
+        input.solve(*part)?;
+    }
+
+    let mut bench = DayBench {
+        parse: Some(measure(config, || day.parse_with(&contents, options))),
+        ..Default::default()
+    };
+    for part in parts {
+        *bench.part_mut(*part) = Some(measure(config, || input.solve(*part)));
+    }
+
+    Ok(bench)
+}
+
+/// Durations as a number of nanoseconds, easier to read than serde's default.
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(millis(&[30, 10, 20])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.median, Duration::from_millis(20));
+        assert_eq!(stats.max, Duration::from_millis(30));
+
+        let stats = Stats::from_samples(millis(&[40, 10, 20, 30])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(25));
+        assert!(Stats::from_samples(vec![]).is_none());
+
+        let baseline = Stats::from_samples(millis(&[20])).unwrap();
+        let change = stats.change_since(&baseline).unwrap();
+        assert!((change - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_measure() {
+        let config = BenchConfig {
+            warmup: 1,
+            iterations: 5,
+            time_limit: Duration::from_secs(60),
+        };
+        let mut calls = 0;
+        let stats = measure(&config, || calls += 1);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(calls, 6);
+
+        let config = BenchConfig {
+            time_limit: Duration::ZERO,
+            ..config
+        };
+        assert_eq!(measure(&config, || ()).samples, 1);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let stats = Stats::from_samples(millis(&[1])).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.update(
+            17,
+            &DayBench {
+                parse: Some(stats),
+                part1: Some(stats),
+                part2: None,
+            },
+        );
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"days":{"17":{"parse":{"samples":1,"min":1000000,"median":1000000,"max":1000000},"part1":{"samples":1,"min":1000000,"median":1000000,"max":1000000}}}}"#
+        );
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
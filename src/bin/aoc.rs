@@ -0,0 +1,650 @@
+use adventofcode::{
+    answers_path, bench_day, generate, get_day, init_logging, input_hash, Animator, Answers,
+    Baseline, BenchConfig, Config, Day, Fixture, FrameExport, FrameSink, ImageFormat, InputSource,
+    Part, RawOptions, Recorder, Report, Stats, Status, DAYS,
+};
+use clap::Parser;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+/// Run the Advent of Code 2022 solutions
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Args {
+    /// Days to run: a single day (`17`), a range (`3..7` or `3-7`) or `all`
+    #[arg(value_parser = parse_days)]
+    days: RangeInclusive<u8>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the input from this file instead, `-` for stdin
+    #[arg(short, long, value_name = "PATH", group = "source")]
+    input: Option<String>,
+
+    /// Read the input from `<user>/dayXX.txt` in the input directory
+    #[arg(short, long, group = "source")]
+    user: Option<String>,
+
+    /// Run on the N-th example from the puzzle text instead, `fixtures/dayXX/exampleN.txt`
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1", group = "source")]
+    example: Option<usize>,
+
+    /// Run on a random input drawn from this seed instead, with `--size`
+    #[arg(short, long, value_name = "SEED", group = "source")]
+    generate: Option<u64>,
+
+    /// Size of the random inputs: the number of lines for most days, the
+    /// side of the map for the grids
+    #[arg(long, value_name = "N", default_value_t = 100, requires = "generate")]
+    size: usize,
+
+    /// Print the input of the days instead of solving them, to keep a
+    /// random input
+    #[arg(long, conflicts_with_all = ["json", "check", "bench"])]
+    print_input: bool,
+
+    /// Read the options of the days from this TOML file, in `[dayXX]` tables
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Set an option, `KEY=VALUE` for every day that has it or `dayXX.KEY=VALUE`
+    /// for one day
+    #[arg(short, long = "option", value_name = "KEY=VALUE")]
+    options: Vec<String>,
+
+    /// Log more to stderr: `-v` for info, `-vv` for debug, `-vvv` for trace.
+    /// `AOC_LOG` sets the levels per day, e.g. `AOC_LOG=day16=debug`
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Print one JSON object per day and part instead, with the answer, its
+    /// status, the time taken and the hash of the input
+    #[arg(long, conflicts_with = "bench")]
+    json: bool,
+
+    /// Compare the answers with the ones recorded in `answers/dayXX.toml`
+    #[arg(long, conflicts_with_all = ["input", "example", "generate"])]
+    check: bool,
+
+    /// Time the parsing and each part, and compare with the saved baseline
+    #[arg(long, conflicts_with = "check")]
+    bench: bool,
+
+    /// Untimed runs before measuring
+    #[arg(long, value_name = "N", default_value_t = 2, requires = "bench")]
+    warmup: usize,
+
+    /// Timed runs of each step
+    #[arg(long, value_name = "N", default_value_t = 10, requires = "bench")]
+    iterations: usize,
+
+    /// Stop the warmup or the timed runs of a step after this many seconds
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 10.0,
+        requires = "bench"
+    )]
+    time_limit: f64,
+
+    /// Baseline to compare the timings with
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "bench-baseline.json",
+        requires = "bench"
+    )]
+    baseline: PathBuf,
+
+    /// Save the timings as the new baseline
+    #[arg(long, requires = "bench")]
+    save_baseline: bool,
+
+    /// Animate the simulations in the terminal instead of just solving them:
+    /// the sand of day 14, the rocks of day 17, the walk of day 22, the elves
+    /// of day 23 and the blizzards of day 24
+    #[arg(long, group = "visual", conflicts_with_all = ["json", "check", "bench", "print_input"])]
+    visualize: bool,
+
+    /// Frames per second of the animation
+    #[arg(long, value_name = "N", default_value_t = 20.0, requires = "visualize")]
+    fps: f64,
+
+    /// Write the frames of the simulations as images in this directory
+    /// instead, `dayXX-partN-00000.ppm` and so on
+    #[arg(
+        long,
+        value_name = "DIR",
+        group = "visual",
+        conflicts_with_all = ["json", "check", "bench", "print_input"]
+    )]
+    frames: Option<PathBuf>,
+
+    /// Format of the frames: `ppm` or `png`
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        default_value = "ppm",
+        requires = "frames"
+    )]
+    image_format: ImageFormat,
+
+    /// Side of a cell in the frames, in pixels
+    #[arg(long, value_name = "PIXELS", default_value_t = 4, requires = "frames")]
+    scale: usize,
+
+    /// Only draw one step of the simulations in N
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "visual")]
+    every: usize,
+}
+
+impl Args {
+    fn input_source(&self) -> InputSource {
+        if let Some(input) = &self.input {
+            InputSource::from_arg(input)
+        } else if let Some(user) = &self.user {
+            InputSource::User(user.clone())
+        } else if let Some(n) = self.example {
+            InputSource::Example(n)
+        } else if let Some(seed) = self.generate {
+            InputSource::Generated {
+                seed,
+                size: self.size,
+            }
+        } else {
+            InputSource::Default
+        }
+    }
+
+    /// The options of the config file, overridden by the ones of the command
+    /// line.
+    fn config(&self) -> Result<Config, String> {
+        let mut config = match &self.config {
+            Some(path) => Config::load(path).map_err(|e| format!("cannot read the config: {e}"))?,
+            None => Config::default(),
+        };
+        for option in &self.options {
+            config.set(option).map_err(|e| e.to_string())?;
+        }
+
+        // an option of every day goes to the days which have it
+        let names: Vec<&str> = self
+            .days
+            .clone()
+            .filter_map(get_day)
+            .flat_map(|day| day.option_names().iter().copied())
+            .collect();
+        config.check_shared(&names).map_err(|e| e.to_string())?;
+        Ok(config)
+    }
+}
+
+/// The options of `day`: the ones the example was recorded with or the
+/// random input was drawn for, overridden by the ones of `config`.
+fn day_options(day: &Day, source: &InputSource, config: &Config) -> Result<RawOptions, String> {
+    let mut options = match source {
+        InputSource::Example(n) => {
+            Fixture::example(day.number, *n)
+                .answers()
+                .map_err(|e| format!("cannot read the answers of the example: {e}"))?
+                .options
+        }
+        InputSource::Generated { seed, size } => {
+            generate(day.number, *seed, *size)
+                .map_err(|e| format!("cannot generate the input: {e}"))?
+                .options
+        }
+        _ => RawOptions::default(),
+    };
+    options.merge(&config.day(day.number, day.option_names()));
+    Ok(options)
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let last = DAYS.len() as u8;
+    let parse_day = |day: &str| -> Result<u8, String> {
+        match day.trim().parse::<u8>() {
+            Ok(n) if (1..=last).contains(&n) => Ok(n),
+            _ => Err(format!("`{day}` is not a day between 1 and {last}")),
+        }
+    };
+
+    if s == "all" {
+        return Ok(1..=last);
+    }
+
+    let bounds = s
+        .split_once("..=")
+        .or_else(|| s.split_once(".."))
+        .or_else(|| s.split_once('-'));
+
+    match bounds {
+        Some((start, end)) => {
+            let (start, end) = (parse_day(start)?, parse_day(end)?);
+            if start > end {
+                return Err(format!("`{s}` is an empty range"));
+            }
+            Ok(start..=end)
+        }
+        None => {
+            let day = parse_day(s)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+/// Reads, parses and solves `day`. Reading or parsing errors fail every part.
+fn solve_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    options: Result<RawOptions, String>,
+) -> Result<Vec<Report>, String> {
+    let options = options?;
+    let contents = day
+        .read_input(source)
+        .map_err(|e| format!("cannot read input for day {:02}: {e}", day.number))?;
+    let hash = input_hash(&contents);
+    let input = day
+        .parse_with(&contents, &options)
+        .map_err(|e| format!("cannot parse input for day {:02}: {e}", day.number))?;
+
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let report = Report::new(day, *part, Some(hash.clone()));
+            let start = Instant::now();
+            match input.solve(*part) {
+                Ok(answer) => report.solved(answer, start.elapsed()),
+                Err(e) => report.failed(e),
+            }
+        })
+        .collect())
+}
+
+/// The reports of `solve_day`, its error failing every part.
+fn day_reports(day: &Day, parts: &[Part], result: Result<Vec<Report>, String>) -> Vec<Report> {
+    result.unwrap_or_else(|e| {
+        parts
+            .iter()
+            .map(|part| Report::new(day, *part, None).failed(&e))
+            .collect()
+    })
+}
+
+fn print_json(report: &Report) {
+    println!(
+        "{}",
+        serde_json::to_string(report).expect("reports are serializable")
+    );
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    config: &Config,
+    json: bool,
+) -> Result<(), String> {
+    let options =
+        day_options(day, source, config).map_err(|e| format!("day {:02}: {e}", day.number));
+    if !json {
+        println!("Day {:02}: {}", day.number, day.title);
+    }
+    let result = solve_day(day, parts, source, options);
+
+    if json {
+        let reports = day_reports(day, parts, result);
+        reports.iter().for_each(print_json);
+        return match reports.iter().any(|report| report.status == Status::Error) {
+            true => Err(format!("day {:02} failed", day.number)),
+            false => Ok(()),
+        };
+    }
+
+    let mut errors = vec![];
+    for (part, report) in parts.iter().zip(result?) {
+        let answer = match (&report.answer, &report.error) {
+            (Some(answer), _) => answer.to_string(),
+            (None, error) => {
+                println!("  {part}: -");
+                let error = error.as_deref().unwrap_or_default();
+                errors.push(format!("day {:02} {part} failed: {error}", day.number));
+                continue;
+            }
+        };
+        if answer.contains('\n') {
+            println!("  {part}:");
+            for line in answer.lines() {
+                println!("    {line}");
+            }
+        } else {
+            println!("  {part}: {answer}");
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    errors: usize,
+}
+
+fn print_row(day: &Day, part: Part, status: &str, details: &str) {
+    println!(
+        "{:>3}  {part}  {status:<7}  {details}",
+        format!("{:02}", day.number)
+    );
+}
+
+/// Shows multi-line answers as their line count, to keep the table readable.
+fn short_answer(answer: &str) -> String {
+    let answer = answer.trim_end();
+    if answer.contains('\n') {
+        format!("<{} lines>", answer.lines().count())
+    } else {
+        answer.to_string()
+    }
+}
+
+fn check_day(day: &Day, parts: &[Part], user: Option<&str>, config: &Config) -> Vec<Report> {
+    let answers = match Answers::load(day.number, user) {
+        Ok(answers) => answers,
+        Err(e) => {
+            let path = answers_path(day.number, user);
+            let error = format!("{}: {e}", path.display());
+            return day_reports(day, parts, Err(error));
+        }
+    };
+    let source = match user {
+        Some(user) => InputSource::User(user.to_string()),
+        None => InputSource::Default,
+    };
+    let mut options = answers.options.clone();
+    options.merge(&config.day(day.number, day.option_names()));
+
+    let result = solve_day(day, parts, &source, Ok(options));
+    day_reports(day, parts, result)
+        .into_iter()
+        .zip(parts)
+        .map(|(report, part)| match &report.answer {
+            Some(answer) => {
+                let check = answers.check(*part, answer);
+                report.checked(&check)
+            }
+            None => report,
+        })
+        .collect()
+}
+
+fn print_check(report: &Report, day: &Day, part: Part, summary: &mut Summary) {
+    let answer = report
+        .answer
+        .as_ref()
+        .map(|answer| short_answer(&answer.to_string()))
+        .unwrap_or_default();
+    match report.status {
+        Status::Pass => {
+            print_row(day, part, "pass", &answer);
+            summary.passed += 1;
+        }
+        Status::Fail => {
+            let expected = short_answer(report.expected.as_deref().unwrap_or_default());
+            print_row(
+                day,
+                part,
+                "FAIL",
+                &format!("{answer} (expected {expected})"),
+            );
+            summary.failed += 1;
+        }
+        Status::Missing | Status::Ok => {
+            print_row(day, part, "missing", &answer);
+            summary.missing += 1;
+        }
+        Status::Error => {
+            print_row(
+                day,
+                part,
+                "error",
+                report.error.as_deref().unwrap_or_default(),
+            );
+            summary.errors += 1;
+        }
+    }
+}
+
+fn check_days(args: &Args, parts: &[Part], config: &Config) -> ExitCode {
+    let mut summary = Summary::default();
+    if !args.json {
+        println!("Day  Part   Status   Answer");
+    }
+    for number in args.days.clone() {
+        let day = get_day(number).expect("every day is registered");
+        let reports = check_day(day, parts, args.user.as_deref(), config);
+        for (report, part) in reports.iter().zip(parts) {
+            if args.json {
+                print_json(report);
+            }
+            match (args.json, report.status) {
+                (false, _) => print_check(report, day, *part, &mut summary),
+                (true, Status::Fail) => summary.failed += 1,
+                (true, Status::Error) => summary.errors += 1,
+                (true, _) => {}
+            }
+        }
+    }
+
+    if !args.json {
+        println!(
+            "\n{} passed, {} failed, {} missing, {} errors",
+            summary.passed, summary.failed, summary.missing, summary.errors
+        );
+    }
+    if summary.failed > 0 || summary.errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_stats(day: &Day, step: &str, stats: &Stats, baseline: Option<&Stats>) {
+    let change = match baseline.and_then(|baseline| stats.change_since(baseline)) {
+        Some(change) => format!("{change:+.1}%"),
+        None => "-".to_string(),
+    };
+    println!(
+        "{:>3}  {step:<5}  {:>7}  {:>10}  {:>10}  {:>10}  {change:>7}",
+        format!("{:02}", day.number),
+        stats.samples,
+        format!("{:.1?}", stats.min),
+        format!("{:.1?}", stats.median),
+        format!("{:.1?}", stats.max),
+    );
+}
+
+fn bench_days(args: &Args, parts: &[Part], options: &Config) -> ExitCode {
+    let config = BenchConfig {
+        warmup: args.warmup,
+        iterations: args.iterations,
+        time_limit: Duration::from_secs_f64(args.time_limit.max(0.0)),
+    };
+    let mut baseline = match Baseline::load(&args.baseline) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("cannot read the baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    println!("Day  Step   Samples         Min      Median         Max   Change");
+    for number in args.days.clone() {
+        let day = get_day(number).expect("every day is registered");
+        let source = args.input_source();
+        let bench = match day_options(day, &source, options).and_then(|options| {
+            bench_day(day, parts, &source, &options, &config).map_err(|e| e.to_string())
+        }) {
+            Ok(bench) => bench,
+            Err(e) => {
+                eprintln!("day {:02} failed: {e}", day.number);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let saved = baseline.days.get(&number);
+        if let Some(stats) = &bench.parse {
+            print_stats(day, "parse", stats, saved.and_then(|s| s.parse.as_ref()));
+        }
+        for part in parts {
+            if let Some(stats) = bench.part(*part) {
+                let step = match part {
+                    Part::One => "part1",
+                    Part::Two => "part2",
+                };
+                print_stats(day, step, stats, saved.and_then(|s| s.part(*part)));
+            }
+        }
+        if args.save_baseline {
+            baseline.update(number, &bench);
+        }
+    }
+
+    if args.save_baseline {
+        if let Err(e) = baseline.save(&args.baseline) {
+            eprintln!("cannot save the baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+        println!("\nbaseline saved to {}", args.baseline.display());
+    }
+    status
+}
+
+/// Plays or exports the simulation of each part of `day`, then prints the
+/// answer.
+fn visualize_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    config: &Config,
+    args: &Args,
+) -> Result<(), String> {
+    let options = day_options(day, source, config)?;
+    let contents = day
+        .read_input(source)
+        .map_err(|e| format!("cannot read input for day {:02}: {e}", day.number))?;
+    let input = day
+        .parse_with(&contents, &options)
+        .map_err(|e| format!("cannot parse input for day {:02}: {e}", day.number))?;
+
+    for part in parts {
+        let mut sink: Box<dyn FrameSink> = match &args.frames {
+            Some(dir) => {
+                let prefix = format!("day{:02}-part{}", day.number, part.number());
+                let export = FrameExport::new(dir, &prefix, args.image_format, args.scale)
+                    .map_err(|e| format!("cannot write the frames to {}: {e}", dir.display()))?;
+                Box::new(export)
+            }
+            None => Box::new(Animator::new(io::stdout(), args.fps)),
+        };
+        let mut recorder = Recorder::new(sink.as_mut(), args.every);
+
+        let Some(answer) = input.visualize(*part, &mut recorder) else {
+            eprintln!("day {:02} is not a simulation, skipped", day.number);
+            return Ok(());
+        };
+        let frames = recorder
+            .finish()
+            .map_err(|e| format!("cannot draw day {:02} {part}: {e}", day.number))?;
+        // the animator shows the cursor again
+        drop(sink);
+
+        match answer {
+            Ok(answer) => println!("Day {:02} {part}: {answer} ({frames} frames)", day.number),
+            Err(e) => return Err(format!("day {:02} {part} failed: {e}", day.number)),
+        }
+    }
+    Ok(())
+}
+
+fn print_inputs(args: &Args, source: &InputSource) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for number in args.days.clone() {
+        let day = get_day(number).expect("every day is registered");
+        match day.read_input(source) {
+            Ok(input) => print!("{input}"),
+            Err(e) => {
+                eprintln!("cannot read input for day {number:02}: {e}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Err(e) = init_logging(args.verbose) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    let source = args.input_source();
+
+    if matches!(source, InputSource::Path(_) | InputSource::Stdin) && args.days.len() > 1 {
+        eprintln!("--input can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+
+    let config = match args.config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = match args.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
+
+    if args.print_input {
+        return print_inputs(&args, &source);
+    }
+    if args.check {
+        return check_days(&args, &parts, &config);
+    }
+    if args.bench {
+        return bench_days(&args, &parts, &config);
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    for number in args.days.clone() {
+        let day = get_day(number).expect("every day is registered");
+        let result = match args.visualize || args.frames.is_some() {
+            true => visualize_day(day, &parts, &source, &config, &args),
+            false => run_day(day, &parts, &source, &config, args.json),
+        };
+        if let Err(e) = result {
+            eprintln!("{e}");
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    status
+}
@@ -0,0 +1,263 @@
+use crate::error::{Error, Result};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How [`find_cycle`] looks for the repeating state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleMethod {
+    /// Remembers the key of every state: the fewest steps, but every key is
+    /// kept.
+    Hashing,
+    /// Floyd's tortoise and hare: two copies of the state and no memory.
+    Floyd,
+    /// Brent's algorithm: like Floyd, with fewer steps.
+    Brent,
+}
+
+/// A cycle of a simulation: the state after `start` steps comes back every
+/// `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+    /// The metric after each step, up to `start + period` included.
+    metrics: Vec<i64>,
+}
+
+impl Cycle {
+    /// The metric after `steps` steps, every cycle adding what the first one
+    /// did. An error if it does not fit in an `i64`.
+    pub fn metric_at(&self, steps: u64) -> Result<i64> {
+        let (start, period) = (self.start as u64, self.period as u64);
+        if steps <= start + period {
+            return Ok(self.metrics[steps as usize]);
+        }
+
+        let cycles = (steps - start) / period;
+        let rest = (steps - start) % period;
+        let per_cycle =
+            self.metrics[self.start + self.period].checked_sub(self.metrics[self.start]);
+        i64::try_from(cycles)
+            .ok()
+            .zip(per_cycle)
+            .and_then(|(cycles, per_cycle)| cycles.checked_mul(per_cycle))
+            .and_then(|total| total.checked_add(self.metrics[(start + rest) as usize]))
+            .ok_or_else(|| Error::overflow(format!("the metric after {steps} steps")))
+    }
+}
+
+/// Runs the simulation from `start`, `step` advancing it by one step,
+/// until a key comes back. `key` is what must repeat: the state itself, or
+/// the part of it that decides what comes next. `metric` is the value to
+/// extrapolate with [`Cycle::metric_at`]. `None` if no cycle shows up in
+/// `max_steps` steps.
+pub fn find_cycle<S, K>(
+    start: S,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    metric: impl Fn(&S) -> i64,
+    method: CycleMethod,
+    max_steps: usize,
+) -> Option<Cycle>
+where
+    S: Clone,
+    K: Eq + Hash,
+{
+    let (cycle_start, period) = match method {
+        CycleMethod::Hashing => {
+            return hashing(start, &mut step, &key, &metric, max_steps);
+        }
+        CycleMethod::Floyd => floyd(&start, &mut step, &key, max_steps)?,
+        CycleMethod::Brent => brent(&start, &mut step, &key, max_steps)?,
+    };
+
+    let mut state = start;
+    let mut metrics = vec![metric(&state)];
+    for _ in 0..cycle_start + period {
+        step(&mut state);
+        metrics.push(metric(&state));
+    }
+    Some(Cycle {
+        start: cycle_start,
+        period,
+        metrics,
+    })
+}
+
+fn hashing<S, K: Eq + Hash>(
+    mut state: S,
+    step: &mut impl FnMut(&mut S),
+    key: &impl Fn(&S) -> K,
+    metric: &impl Fn(&S) -> i64,
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::from([(key(&state), 0)]);
+    let mut metrics = vec![metric(&state)];
+
+    for steps in 1..=max_steps {
+        step(&mut state);
+        metrics.push(metric(&state));
+        match seen.entry(key(&state)) {
+            Entry::Occupied(first) => {
+                return Some(Cycle {
+                    start: *first.get(),
+                    period: steps - first.get(),
+                    metrics,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+    }
+    None
+}
+
+// the start and the period of the cycle
+fn floyd<S: Clone, K: Eq>(
+    start: &S,
+    step: &mut impl FnMut(&mut S),
+    key: &impl Fn(&S) -> K,
+    max_steps: usize,
+) -> Option<(usize, usize)> {
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut tortoise);
+    step(&mut hare);
+    step(&mut hare);
+
+    // the hare is in the cycle, a multiple of the period ahead
+    let mut steps = 1;
+    while key(&tortoise) != key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+        steps += 1;
+    }
+
+    // both meet at the start of the cycle
+    let mut cycle_start = 0;
+    tortoise = start.clone();
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        cycle_start += 1;
+    }
+
+    let mut period = 1;
+    hare = tortoise.clone();
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        step(&mut hare);
+        period += 1;
+    }
+    Some((cycle_start, period))
+}
+
+// the start and the period of the cycle
+fn brent<S: Clone, K: Eq>(
+    start: &S,
+    step: &mut impl FnMut(&mut S),
+    key: &impl Fn(&S) -> K,
+    max_steps: usize,
+) -> Option<(usize, usize)> {
+    // the tortoise waits at powers of two for the hare to come back
+    let mut power = 1;
+    let mut period = 1;
+    let mut steps = 1;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // a hare one period ahead meets the tortoise at the start of the cycle
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut cycle_start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        cycle_start += 1;
+    }
+    Some((cycle_start, period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METHODS: [CycleMethod; 3] =
+        [CycleMethod::Hashing, CycleMethod::Floyd, CycleMethod::Brent];
+
+    #[test]
+    fn test_find_cycle() {
+        // x -> x² + 1 mod 255 from 3: 3, 10, 101, 2, 5, 26, 167, 95, 101...
+        // with the sum of the values as the metric
+        let start = (3u64, 3i64);
+        let step = |(x, sum): &mut (u64, i64)| {
+            *x = (*x * *x + 1) % 255;
+            *sum += *x as i64;
+        };
+
+        for method in METHODS {
+            let cycle = find_cycle(start, step, |s| s.0, |s| s.1, method, 100).unwrap();
+            assert_eq!((cycle.start, cycle.period), (2, 6), "{method:?}");
+
+            let mut state = start;
+            for steps in 0..50 {
+                assert_eq!(
+                    cycle.metric_at(steps).unwrap(),
+                    state.1,
+                    "{method:?} {steps}"
+                );
+                step(&mut state);
+            }
+        }
+    }
+
+    #[test]
+    fn test_metric_overflow() {
+        // 1000 more at every step
+        let cycle = find_cycle(
+            (0u8, 0i64),
+            |(x, sum)| {
+                *x = (*x + 1) % 2;
+                *sum += 1000;
+            },
+            |s| s.0,
+            |s| s.1,
+            CycleMethod::Hashing,
+            10,
+        )
+        .unwrap();
+        assert_eq!(cycle.metric_at(1_000_000).unwrap(), 1_000_000_000);
+        assert!(matches!(cycle.metric_at(u64::MAX), Err(Error::Overflow(_))));
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        for method in METHODS {
+            let cycle = find_cycle(0u64, |x| *x += 1, |x| *x, |x| *x as i64, method, 1000);
+            assert_eq!(cycle, None, "{method:?}");
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::parse::Line;
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use log::debug;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Inventory;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(inventory: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(inventory)?.into())
+    }
+
+    fn part2(inventory: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(inventory)?.into())
+    }
+}
+
+/// An elf of the inventory: its place in the input, 0 for the first one,
+/// and the calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The calories carried by the elves, added one elf at a time so that the
+/// input never has to be held whole. Only the `n` largest totals are kept,
+/// in a min-heap, with the number of elves and their sum. Every total is
+/// kept as well with [`Inventory::with_median`].
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    n: usize,
+    // the smallest kept total on top, the last elf first for a tie
+    top: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    elves: usize,
+    sum: u128,
+    totals: Option<Vec<u64>>,
+}
+
+impl Inventory {
+    /// An empty inventory keeping the `n` largest totals.
+    pub fn new(n: usize) -> Self {
+        Inventory {
+            n,
+            top: BinaryHeap::with_capacity(n),
+            elves: 0,
+            sum: 0,
+            totals: None,
+        }
+    }
+
+    /// Keeps every total too, for [`Inventory::median`].
+    pub fn with_median(mut self) -> Self {
+        self.totals = Some(vec![]);
+        self
+    }
+
+    /// Adds the next elf. It only replaces a kept one if it carries more,
+    /// the first elves winning the ties.
+    pub fn add(&mut self, calories: u64) {
+        let elf = Reverse((calories, Reverse(self.elves)));
+        if self.top.len() < self.n {
+            self.top.push(elf);
+        } else if let Some(mut smallest) = self.top.peek_mut() {
+            if elf < *smallest {
+                *smallest = elf;
+            }
+        }
+
+        self.elves += 1;
+        self.sum += calories as u128;
+        if let Some(totals) = &mut self.totals {
+            totals.push(calories);
+        }
+    }
+
+    /// Adds the elves of `reader`, a block of lines each, one line per snack.
+    /// Only the elf being read is held in memory.
+    pub fn read(mut self, reader: impl BufRead) -> Result<Self> {
+        let mut elf: Option<u64> = None;
+        for (i, text) in reader.lines().enumerate() {
+            let text = text?;
+            if text.trim().is_empty() {
+                if let Some(calories) = elf.take() {
+                    self.add(calories);
+                }
+                continue;
+            }
+
+            let line = Line {
+                number: i + 1,
+                text: &text,
+            };
+            let calories = elf
+                .unwrap_or(0)
+                .checked_add(line.value()?)
+                .ok_or_else(|| Error::overflow(format!("the calories of elf {}", self.elves)))?;
+            elf = Some(calories);
+        }
+        if let Some(calories) = elf {
+            self.add(calories);
+        }
+        Ok(self)
+    }
+
+    /// The kept elves, from the one carrying the most.
+    pub fn top(&self) -> Vec<Elf> {
+        self.top
+            .clone()
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect()
+    }
+
+    pub fn elves(&self) -> usize {
+        self.elves
+    }
+
+    /// `None` without elves.
+    pub fn mean(&self) -> Option<f64> {
+        (self.elves > 0).then(|| self.sum as f64 / self.elves as f64)
+    }
+
+    /// The middle total, or the mean of the two middle ones. `None` without
+    /// elves, or if the totals were not kept.
+    pub fn median(&self) -> Option<f64> {
+        let mut totals = self.totals.clone().filter(|totals| !totals.is_empty())?;
+        let middle = totals.len() / 2;
+        let (lower, median, _) = totals.select_nth_unstable(middle);
+        let median = *median as f64;
+
+        match lower.iter().max() {
+            Some(&below) if middle * 2 == self.elves => Some((below as f64 + median) / 2.0),
+            _ => Some(median),
+        }
+    }
+}
+
+pub fn parse_input(contents: &str) -> Result<Inventory> {
+    Inventory::new(3).read(contents.as_bytes())
+}
+
+pub fn part1(inventory: &Inventory) -> Result<u64> {
+    let elf = inventory
+        .top()
+        .first()
+        .copied()
+        .ok_or_else(|| Error::no_solution("there are no elves"))?;
+    debug!("elf {} carries the most calories", elf.index);
+    Ok(elf.calories)
+}
+
+pub fn part2(inventory: &Inventory) -> Result<u64> {
+    let top = inventory.top();
+    let [first, second, third, ..] = top[..] else {
+        return Err(Error::no_solution("there are less than 3 elves"));
+    };
+    debug!(
+        "{} elves carry {:.1} calories on average",
+        inventory.elves(),
+        inventory.mean().unwrap_or_default()
+    );
+
+    [second, third]
+        .iter()
+        .try_fold(first.calories, |sum, elf| sum.checked_add(elf.calories))
+        .ok_or_else(|| Error::overflow("the calories of the top 3 elves"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top() {
+        let mut inventory = Inventory::new(2).with_median();
+        for calories in [5, 9, 1, 9, 7] {
+            inventory.add(calories);
+        }
+        let elf = |index, calories| Elf { index, calories };
+        assert_eq!(inventory.top(), vec![elf(1, 9), elf(3, 9)]);
+        assert_eq!(inventory.elves(), 5);
+        assert_eq!(inventory.mean(), Some(6.2));
+        assert_eq!(inventory.median(), Some(7.0));
+
+        inventory.add(6);
+        assert_eq!(inventory.median(), Some(6.5));
+        assert_eq!(Inventory::new(3).add_all([4]).median(), None);
+        assert!(Inventory::new(0).add_all([4]).top().is_empty());
+        assert_eq!(Inventory::new(3).mean(), None);
+    }
+
+    #[test]
+    fn test_read() {
+        let inventory = Inventory::new(1)
+            .read("\n1000\n2000\n\n\n4000\n\n1\n".as_bytes())
+            .unwrap();
+        assert_eq!(
+            inventory.top(),
+            vec![Elf {
+                index: 1,
+                calories: 4000
+            }]
+        );
+        assert_eq!(inventory.elves(), 3);
+
+        let error = Inventory::new(1).read("1\n\nx2\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid value `x2`: invalid digit found in string"
+        );
+        let error = Inventory::new(1)
+            .read(format!("{}\n{}\n", u64::MAX, 1).as_bytes())
+            .unwrap_err();
+        assert!(matches!(error, Error::Overflow(_)));
+    }
+
+    impl Inventory {
+        fn add_all(mut self, totals: impl IntoIterator<Item = u64>) -> Self {
+            totals.into_iter().for_each(|calories| self.add(calories));
+            self
+        }
+    }
+}
@@ -0,0 +1,556 @@
+use crate::error::Location;
+use crate::parse::{lines, Line};
+use crate::{Answer, Error, Result, Solution};
+use log::{debug, info};
+use serde::Deserialize;
+use std::fmt;
+
+/// The rules of the game. The defaults are Rock Paper Scissors as in the
+/// puzzle: `A`/`X` rock, `B`/`Y` paper and `C`/`Z` scissors.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// The symbols of the opponent's hands, an odd number of them, in the
+    /// order of the cycle: each hand beats the half of the others just before
+    /// it and loses to the half just after it.
+    pub opponent: String,
+    /// The symbols of the second column, one per hand. In part 1 they are
+    /// the hands, in the same order. In part 2 they are the hand to play
+    /// from the opponent's: the middle symbol is a draw, the ones before it a
+    /// loss and the ones after it a win.
+    pub me: String,
+    /// The score of each hand, `1, 2, 3...` when empty.
+    pub shape_scores: Vec<u32>,
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+    /// Logs the score of every interpretation of the second column, at the
+    /// info level, and the rounds of the best and the worst one at the debug
+    /// level.
+    pub analyze: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            opponent: "ABC".to_string(),
+            me: "XYZ".to_string(),
+            shape_scores: vec![],
+            loss: 0,
+            draw: 3,
+            win: 6,
+            analyze: false,
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(guide: &Self::Input, options: &Options) -> Result<Answer> {
+        let game = Game::new(options)?;
+        if options.analyze {
+            log_analysis(guide, &game, Meaning::Hand)?;
+        }
+        Ok(part1(guide, &game)?.into())
+    }
+
+    fn part2(guide: &Self::Input, options: &Options) -> Result<Answer> {
+        let game = Game::new(options)?;
+        if options.analyze {
+            log_analysis(guide, &game, Meaning::Outcome)?;
+        }
+        Ok(part2(guide, &game)?.into())
+    }
+}
+
+/// A round of the strategy guide, `A Y`: the symbols of its two columns and
+/// its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: char,
+    pub me: char,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// What the second column of the guide means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Meaning {
+    /// The hand to play, as in part 1.
+    Hand,
+    /// The hand to play from the opponent's, as in part 2.
+    Outcome,
+}
+
+/// A game of hands in a cycle, like Rock Paper Scissors or Rock Paper
+/// Scissors Lizard Spock, with its symbols and scores. The hands are
+/// numbered in the order of the cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    opponent: Vec<char>,
+    me: Vec<char>,
+    shape_scores: Vec<u32>,
+    outcome_scores: [u32; 3],
+}
+
+impl Game {
+    /// The game of `options`, an error if they do not describe one.
+    pub fn new(options: &Options) -> Result<Self> {
+        let opponent: Vec<char> = options.opponent.chars().collect();
+        let me: Vec<char> = options.me.chars().collect();
+        let hands = opponent.len();
+        if hands.is_multiple_of(2) {
+            return Err(Error::invalid_option(format!(
+                "`opponent` must have an odd number of hands, found {hands}"
+            )));
+        }
+        if me.len() != hands {
+            return Err(Error::invalid_option(format!(
+                "`me` must have a symbol for each of the {hands} hands, found {}",
+                me.len()
+            )));
+        }
+        for (name, symbols) in [("opponent", &opponent), ("me", &me)] {
+            if let Some((i, symbol)) = symbols
+                .iter()
+                .enumerate()
+                .find(|(i, symbol)| symbols[..*i].contains(symbol) || symbol.is_whitespace())
+            {
+                return Err(Error::invalid_option(format!(
+                    "`{name}` has `{symbol}` at {i}, symbols must be unique and visible"
+                )));
+            }
+        }
+
+        let shape_scores = match options.shape_scores.is_empty() {
+            true => (1..=hands as u32).collect(),
+            false => options.shape_scores.clone(),
+        };
+        if shape_scores.len() != hands {
+            return Err(Error::invalid_option(format!(
+                "`shape_scores` must have a score for each of the {hands} hands, found {}",
+                shape_scores.len()
+            )));
+        }
+
+        Ok(Game {
+            opponent,
+            me,
+            shape_scores,
+            outcome_scores: [options.loss, options.draw, options.win],
+        })
+    }
+
+    pub fn hands(&self) -> usize {
+        self.opponent.len()
+    }
+
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        let hands = self.hands();
+        match (mine + hands - theirs) % hands {
+            0 => Outcome::Draw,
+            ahead if ahead <= hands / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The score of playing `mine` against `theirs`.
+    pub fn score(&self, mine: usize, theirs: usize) -> u64 {
+        self.shape_scores[mine] as u64
+            + self.outcome_scores[self.outcome(mine, theirs) as usize] as u64
+    }
+
+    /// The hand to play against `theirs` for the symbol `symbol` of the
+    /// second column, its index in [`Options::me`].
+    pub fn my_hand(&self, theirs: usize, symbol: usize, meaning: Meaning) -> usize {
+        let hands = self.hands();
+        match meaning {
+            Meaning::Hand => symbol,
+            Meaning::Outcome => (theirs + symbol + hands - hands / 2) % hands,
+        }
+    }
+
+    /// The opponent's hand and the index of the second symbol of `round`.
+    pub fn symbols(&self, round: &Round) -> Result<(usize, usize)> {
+        let find = |symbols: &[char], symbol: char, column: usize| {
+            symbols.iter().position(|s| *s == symbol).ok_or_else(|| {
+                let expected: String = symbols.iter().collect();
+                Error::invalid(
+                    Location {
+                        line: round.line,
+                        column,
+                    },
+                    &symbol.to_string(),
+                    format!("not a symbol of the game, expected one of `{expected}`"),
+                )
+            })
+        };
+        Ok((
+            find(&self.opponent, round.opponent, 1)?,
+            find(&self.me, round.me, 3)?,
+        ))
+    }
+
+    /// The total score of following `guide`.
+    pub fn play(&self, guide: &[Round], meaning: Meaning) -> Result<u64> {
+        guide.iter().try_fold(0u64, |total, round| {
+            let (theirs, symbol) = self.symbols(round)?;
+            let score = self.score(self.my_hand(theirs, symbol, meaning), theirs);
+            total
+                .checked_add(score)
+                .ok_or_else(|| Error::overflow("the total score"))
+        })
+    }
+}
+
+/// The games with more hands have too many interpretations to try them all.
+pub const MAX_ANALYZED_HANDS: usize = 9;
+
+/// A reading of the second column of the guide: the symbol `i` stands for
+/// the hand or the outcome of the symbol `mapping[i]` in the puzzle's
+/// reading, with `meaning`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub meaning: Meaning,
+    pub mapping: Vec<usize>,
+    pub total: u64,
+}
+
+/// Every interpretation of the second column with a meaning, each symbol
+/// standing for a different hand or outcome, in the lexicographic order of
+/// their mappings. The puzzle's reading is the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub interpretations: Vec<Interpretation>,
+}
+
+impl Analysis {
+    /// The interpretation with the highest total, the first one for a tie.
+    pub fn best(&self) -> &Interpretation {
+        self.interpretations
+            .iter()
+            .rev()
+            .max_by_key(|interpretation| interpretation.total)
+            .unwrap()
+    }
+
+    /// The interpretation with the lowest total, the first one for a tie.
+    pub fn worst(&self) -> &Interpretation {
+        self.interpretations
+            .iter()
+            .min_by_key(|interpretation| interpretation.total)
+            .unwrap()
+    }
+}
+
+/// A round of the guide as played by an interpretation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub line: usize,
+    pub theirs: usize,
+    pub mine: usize,
+    pub outcome: Outcome,
+    pub score: u64,
+}
+
+impl Game {
+    /// Scores every interpretation of the second column with `meaning`. The
+    /// rounds are only read once, an interpretation scoring each pair of
+    /// symbols with its number of rounds.
+    pub fn analyze(&self, guide: &[Round], meaning: Meaning) -> Result<Analysis> {
+        let hands = self.hands();
+        if hands > MAX_ANALYZED_HANDS {
+            return Err(Error::invalid_option(format!(
+                "a game of {hands} hands has too many interpretations, {MAX_ANALYZED_HANDS} at most"
+            )));
+        }
+
+        let mut counts = vec![vec![0u64; hands]; hands];
+        for round in guide {
+            let (theirs, symbol) = self.symbols(round)?;
+            counts[theirs][symbol] += 1;
+        }
+
+        let mut interpretations = vec![];
+        let mut mapping: Vec<usize> = (0..hands).collect();
+        loop {
+            let mut total = 0u64;
+            for (theirs, counts) in counts.iter().enumerate() {
+                for (symbol, count) in counts.iter().enumerate() {
+                    let score = self.score(self.my_hand(theirs, mapping[symbol], meaning), theirs);
+                    total = score
+                        .checked_mul(*count)
+                        .and_then(|score| total.checked_add(score))
+                        .ok_or_else(|| Error::overflow("the total score"))?;
+                }
+            }
+            interpretations.push(Interpretation {
+                meaning,
+                mapping: mapping.clone(),
+                total,
+            });
+
+            if !next_permutation(&mut mapping) {
+                return Ok(Analysis { interpretations });
+            }
+        }
+    }
+
+    /// Each round of `guide` as played by `interpretation`.
+    pub fn breakdown(&self, guide: &[Round], interpretation: &Interpretation) -> Result<Vec<Play>> {
+        guide
+            .iter()
+            .map(|round| {
+                let (theirs, symbol) = self.symbols(round)?;
+                let mine = self.my_hand(
+                    theirs,
+                    interpretation.mapping[symbol],
+                    interpretation.meaning,
+                );
+                Ok(Play {
+                    line: round.line,
+                    theirs,
+                    mine,
+                    outcome: self.outcome(mine, theirs),
+                    score: self.score(mine, theirs),
+                })
+            })
+            .collect()
+    }
+
+    /// `X=A Y=B Z=C` for hands, named by the opponent's symbols, and
+    /// `X=-1 Y=0 Z=+1` for outcomes, the offset of the hand to play from the
+    /// opponent's.
+    pub fn describe(&self, interpretation: &Interpretation) -> String {
+        let middle = self.hands() / 2;
+        self.me
+            .iter()
+            .zip(&interpretation.mapping)
+            .map(|(symbol, target)| match interpretation.meaning {
+                Meaning::Hand => format!("{symbol}={}", self.opponent[*target]),
+                Meaning::Outcome if *target == middle => format!("{symbol}=0"),
+                Meaning::Outcome => format!("{symbol}={:+}", *target as i64 - middle as i64),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let outcome = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        write!(f, "{outcome}")
+    }
+}
+
+// rearranges `items` into the next permutation in the lexicographic order,
+// false after the last one
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(i) = (1..items.len()).rev().find(|i| items[i - 1] < items[*i]) else {
+        return false;
+    };
+    let j = (i..items.len())
+        .rev()
+        .find(|j| items[*j] > items[i - 1])
+        .unwrap();
+    items.swap(i - 1, j);
+    items[i..].reverse();
+    true
+}
+
+fn log_analysis(guide: &[Round], game: &Game, meaning: Meaning) -> Result<()> {
+    let analysis = game.analyze(guide, meaning)?;
+    for interpretation in &analysis.interpretations {
+        info!(
+            "{}: {}",
+            game.describe(interpretation),
+            interpretation.total
+        );
+    }
+
+    for (name, interpretation) in [("best", analysis.best()), ("worst", analysis.worst())] {
+        info!(
+            "{name}: {} with {}",
+            game.describe(interpretation),
+            interpretation.total
+        );
+        for play in game.breakdown(guide, interpretation)? {
+            debug!(
+                "line {}: {} against {}, {} for {}",
+                play.line,
+                game.opponent[play.mine],
+                game.opponent[play.theirs],
+                play.outcome,
+                play.score
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Round>> {
+    lines(input)
+        .map(|line| {
+            let [opponent, me] = line.split_n(" ", "`<opponent> <me>`")?;
+            Ok(Round {
+                opponent: symbol(&line, opponent)?,
+                me: symbol(&line, me)?,
+                line: line.number,
+            })
+        })
+        .collect()
+}
+
+// the symbols are checked against the game when playing, the rules being
+// options
+fn symbol(line: &Line, field: &str) -> Result<char> {
+    let mut chars = field.chars();
+    match (chars.next(), chars.next()) {
+        (Some(symbol), None) => Ok(symbol),
+        _ => Err(line.malformed(field, "a single symbol")),
+    }
+}
+
+pub fn part1(guide: &[Round], game: &Game) -> Result<u64> {
+    game.play(guide, Meaning::Hand)
+}
+
+pub fn part2(guide: &[Round], game: &Game) -> Result<u64> {
+    game.play(guide, Meaning::Outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // rock, Spock, paper, lizard, scissors: each one beats the two before it
+    fn rpsls() -> Game {
+        Game::new(&Options {
+            opponent: "RKPLS".to_string(),
+            me: "VWXYZ".to_string(),
+            ..Options::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_outcome() {
+        let game = rpsls();
+        let [rock, spock, paper, lizard, scissors] = [0, 1, 2, 3, 4];
+        for (winner, loser) in [
+            (rock, scissors),
+            (rock, lizard),
+            (paper, rock),
+            (paper, spock),
+            (scissors, paper),
+            (scissors, lizard),
+            (lizard, spock),
+            (lizard, paper),
+            (spock, scissors),
+            (spock, rock),
+        ] {
+            assert_eq!(game.outcome(winner, loser), Outcome::Win);
+            assert_eq!(game.outcome(loser, winner), Outcome::Loss);
+        }
+        assert_eq!(game.outcome(lizard, lizard), Outcome::Draw);
+
+        // V and W lose, X draws, Y and Z win
+        let outcomes: Vec<Outcome> = (0..5)
+            .map(|symbol| game.outcome(game.my_hand(paper, symbol, Meaning::Outcome), paper))
+            .collect();
+        use Outcome::*;
+        assert_eq!(outcomes, [Loss, Loss, Draw, Win, Win]);
+    }
+
+    #[test]
+    fn test_rules() {
+        let game = |opponent: &str, me: &str, shape_scores: Vec<u32>| {
+            Game::new(&Options {
+                opponent: opponent.to_string(),
+                me: me.to_string(),
+                shape_scores,
+                ..Options::default()
+            })
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+        };
+        assert_eq!(game("ABCDE", "VWXYZ", vec![5, 4, 3, 2, 1]), Ok(()));
+        assert!(game("ABCD", "WXYZ", vec![]).unwrap_err().contains("odd"));
+        assert!(game("ABC", "XY", vec![]).unwrap_err().contains("`me`"));
+        assert!(game("ABA", "XYZ", vec![]).unwrap_err().contains("unique"));
+        assert!(game("ABC", "XYZ", vec![1]).unwrap_err().contains("score"));
+
+        let guide = parse_input("A Y\nB Q\n").unwrap();
+        assert_eq!(
+            part1(&guide, &Game::new(&Options::default()).unwrap())
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: invalid value `Q`: not a symbol of the game, expected one of `XYZ`"
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let game = Game::new(&Options::default()).unwrap();
+        let guide = parse_input("A Y\nB X\nC Z\n").unwrap();
+
+        let analysis = game.analyze(&guide, Meaning::Hand).unwrap();
+        let totals: Vec<(String, u64)> = analysis
+            .interpretations
+            .iter()
+            .map(|interpretation| (game.describe(interpretation), interpretation.total))
+            .collect();
+        let expected = [
+            ("X=A Y=B Z=C", 15),
+            ("X=A Y=C Z=B", 6),
+            ("X=B Y=A Z=C", 15),
+            ("X=B Y=C Z=A", 15),
+            ("X=C Y=A Z=B", 15),
+            ("X=C Y=B Z=A", 24),
+        ];
+        assert_eq!(
+            totals,
+            expected.map(|(name, total)| (name.to_string(), total))
+        );
+        assert_eq!(analysis.best().mapping, [2, 1, 0]);
+        assert_eq!(analysis.worst().mapping, [0, 2, 1]);
+
+        let analysis = game.analyze(&guide, Meaning::Outcome).unwrap();
+        assert_eq!(analysis.interpretations[0].total, 12);
+        assert_eq!(game.describe(&analysis.interpretations[0]), "X=-1 Y=0 Z=+1");
+        let best = analysis.best();
+        assert_eq!(best.total, 18);
+        let plays = game.breakdown(&guide, best).unwrap();
+        let outcomes: Vec<Outcome> = plays.iter().map(|play| play.outcome).collect();
+        assert_eq!(outcomes, [Outcome::Loss, Outcome::Win, Outcome::Draw]);
+        assert_eq!(plays.iter().map(|play| play.score).sum::<u64>(), best.total);
+    }
+
+    #[test]
+    fn test_next_permutation() {
+        let mut items = [0, 1, 2, 3];
+        let mut count = 1;
+        while next_permutation(&mut items) {
+            count += 1;
+        }
+        assert_eq!((count, items), (24, [3, 2, 1, 0]));
+    }
+}
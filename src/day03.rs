@@ -0,0 +1,330 @@
+use crate::parse::lines;
+use crate::{Answer, Error, Result, Solution};
+use log::trace;
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Elves per group in part 2.
+    pub group_size: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { group_size: 3 }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(rucksacks: &Self::Input, _: &Options) -> Result<Answer> {
+        Ok(part1(rucksacks)?.into())
+    }
+
+    fn part2(rucksacks: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part2(rucksacks, options.group_size)?.into())
+    }
+}
+
+/// A set of items, bit `p` being the item of priority `p`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub const EMPTY: Items = Items(0);
+    /// Every item from `a` to `Z`.
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    pub fn insert(&mut self, priority: u8) {
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(self, priority: u8) -> bool {
+        self.0 & (1 << priority) != 0
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the items, from the lowest.
+    pub fn priorities(self) -> impl Iterator<Item = u8> {
+        (1..=52).filter(move |priority| self.contains(*priority))
+    }
+
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().filter_map(priority_to_item)
+    }
+}
+
+impl FromIterator<u8> for Items {
+    fn from_iter<I: IntoIterator<Item = u8>>(priorities: I) -> Self {
+        let mut items = Items::EMPTY;
+        priorities
+            .into_iter()
+            .for_each(|priority| items.insert(priority));
+        items
+    }
+}
+
+/// The items, `abC`.
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{item}"))
+    }
+}
+
+/// A rucksack and the items of its two compartments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rucksack {
+    pub first: Items,
+    pub second: Items,
+}
+
+impl Rucksack {
+    /// The items in both compartments.
+    pub fn shared(&self) -> Items {
+        self.first.intersection(self.second)
+    }
+
+    /// The items in either compartment.
+    pub fn items(&self) -> Items {
+        self.first.union(self.second)
+    }
+}
+
+/// A rucksack or a group of elves which does not have exactly one item in
+/// common. The rucksacks and groups are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SharedItemError {
+    NoSharedItem { rucksack: usize },
+    SeveralSharedItems { rucksack: usize, items: Items },
+    NoBadge { group: usize },
+    SeveralBadges { group: usize, badges: Items },
+}
+
+impl fmt::Display for SharedItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SharedItemError::NoSharedItem { rucksack } => {
+                write!(f, "rucksack {rucksack} has no item in both compartments")
+            }
+            SharedItemError::SeveralSharedItems { rucksack, items } => {
+                write!(f, "rucksack {rucksack} has `{items}` in both compartments")
+            }
+            SharedItemError::NoBadge { group } => write!(f, "group {group} has no badge"),
+            SharedItemError::SeveralBadges { group, badges } => {
+                write!(f, "group {group} has several badges, `{badges}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SharedItemError {}
+
+impl From<SharedItemError> for Error {
+    fn from(e: SharedItemError) -> Self {
+        Error::unsolvable(e)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack>> {
+    lines(input)
+        .map(|line| {
+            let rucksack = line.text.trim();
+            if let Some((i, c)) = rucksack
+                .char_indices()
+                .find(|(_, c)| item_to_priority(*c).is_none())
+            {
+                let item = &rucksack[i..i + c.len_utf8()];
+                return Err(line.malformed(item, "an item between `a` and `Z`"));
+            }
+            if !rucksack.len().is_multiple_of(2) {
+                return Err(line.invalid(rucksack, "compartments must have the same size"));
+            }
+
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            let items =
+                |compartment: &str| compartment.chars().filter_map(item_to_priority).collect();
+            Ok(Rucksack {
+                first: items(first),
+                second: items(second),
+            })
+        })
+        .collect()
+}
+
+pub fn item_to_priority(c: char) -> Option<u8> {
+    match c {
+        'a'..='z' => Some((c as u8) - 96),
+        'A'..='Z' => Some((c as u8) - 38),
+        _ => None,
+    }
+}
+
+pub fn priority_to_item(priority: u8) -> Option<char> {
+    match priority {
+        1..=26 => Some((priority + 96) as char),
+        27..=52 => Some((priority + 38) as char),
+        _ => None,
+    }
+}
+
+/// The items of every rucksack of `group`.
+pub fn common_items(group: &[Rucksack]) -> Items {
+    group.iter().fold(Items::ALL, |common, rucksack| {
+        common.intersection(rucksack.items())
+    })
+}
+
+/// The item in both compartments of each rucksack.
+pub fn shared_items(rucksacks: &[Rucksack]) -> std::result::Result<Vec<u8>, SharedItemError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let items = rucksack.shared();
+            trace!("rucksack {}: `{}` shared", i + 1, items);
+            match items.len() {
+                0 => Err(SharedItemError::NoSharedItem { rucksack: i + 1 }),
+                1 => Ok(items.priorities().next().unwrap()),
+                _ => Err(SharedItemError::SeveralSharedItems {
+                    rucksack: i + 1,
+                    items,
+                }),
+            }
+        })
+        .collect()
+}
+
+/// The badge of each group of `group_size` elves, the only item all of them
+/// carry.
+pub fn badges(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+) -> std::result::Result<Vec<u8>, SharedItemError> {
+    rucksacks
+        .chunks(group_size)
+        .enumerate()
+        .map(|(i, group)| {
+            let badges = common_items(group);
+            match badges.len() {
+                0 => Err(SharedItemError::NoBadge { group: i + 1 }),
+                1 => Ok(badges.priorities().next().unwrap()),
+                _ => Err(SharedItemError::SeveralBadges {
+                    group: i + 1,
+                    badges,
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32> {
+    Ok(shared_items(rucksacks)?.iter().map(|p| *p as u32).sum())
+}
+
+pub fn part2(rucksacks: &[Rucksack], group_size: usize) -> Result<u32> {
+    if group_size == 0 {
+        return Err(Error::invalid_option("`group_size` must be positive"));
+    }
+    if !rucksacks.len().is_multiple_of(group_size) {
+        return Err(Error::no_solution(format!(
+            "the elves are not in groups of {group_size}"
+        )));
+    }
+    Ok(badges(rucksacks, group_size)?
+        .iter()
+        .map(|p| *p as u32)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        for item in ('a'..='z').chain('A'..='Z') {
+            assert_eq!(
+                item_to_priority(item).and_then(priority_to_item),
+                Some(item)
+            );
+        }
+        let items: Items = "vJrwpWtwJgWr"
+            .chars()
+            .filter_map(item_to_priority)
+            .collect();
+        assert_eq!(items.to_string(), "gprtvwJW");
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::ALL.to_string().len(), 52);
+    }
+
+    #[test]
+    fn test_shared_items() {
+        let rucksacks = parse_input("abca\nabab\nabcd\n").unwrap();
+        assert_eq!(shared_items(&rucksacks[..1]), Ok(vec![1]));
+        let items = "ab".chars().filter_map(item_to_priority).collect();
+        assert_eq!(
+            shared_items(&rucksacks),
+            Err(SharedItemError::SeveralSharedItems { rucksack: 2, items })
+        );
+        assert_eq!(
+            shared_items(&rucksacks[2..]),
+            Err(SharedItemError::NoSharedItem { rucksack: 1 })
+        );
+    }
+
+    #[test]
+    fn test_badges() {
+        let rucksacks = parse_input("abcd\naxby\nzaAb\nAAAA\n").unwrap();
+        assert_eq!(
+            badges(&rucksacks[..1], 1),
+            Err(SharedItemError::SeveralBadges {
+                group: 1,
+                badges: rucksacks[0].items(),
+            })
+        );
+        assert_eq!(
+            badges(&rucksacks, 2),
+            Err(SharedItemError::SeveralBadges {
+                group: 1,
+                badges: "ab".chars().filter_map(item_to_priority).collect(),
+            })
+        );
+        assert_eq!(badges(&rucksacks[2..], 2), Ok(vec![27]));
+        assert_eq!(
+            part2(&rucksacks[1..], 3).unwrap_err().to_string(),
+            "no solution: group 1 has no badge"
+        );
+        assert!(part2(&rucksacks, 3).is_err());
+
+        // the error of the day is kept as the source
+        let error = part1(&rucksacks).unwrap_err();
+        let source = std::error::Error::source(&error).and_then(|e| e.downcast_ref());
+        assert_eq!(source, Some(&SharedItemError::NoSharedItem { rucksack: 1 }));
+        assert!(part2(&rucksacks, 0).is_err());
+    }
+}
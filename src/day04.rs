@@ -0,0 +1,298 @@
+use crate::parse::{lines, Line};
+use crate::{Answer, NoOptions, Result, Solution};
+use log::{debug, log_enabled, Level};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// An interval of sections, both ends included.
+///
+/// The sections are whole numbers, so two intervals meet when one ends
+/// right before the other starts, `2-4` and `5-6`: they do not share a
+/// section but nothing is left between them. `2-4` and `4-6` overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: u16,
+    end: u16,
+}
+
+/// How an interval lies against another one, one of the thirteen relations
+/// of Allen's interval algebra. Exactly one of them holds for a pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Relation {
+    /// Ends before the other starts, with sections between them.
+    Before,
+    /// Ends right before the other starts.
+    Meets,
+    /// Starts first and ends inside the other.
+    Overlaps,
+    /// Starts with the other and ends first.
+    Starts,
+    /// Strictly inside the other.
+    During,
+    /// Ends with the other and starts last.
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 13] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::Overlaps,
+        Relation::Starts,
+        Relation::During,
+        Relation::Finishes,
+        Relation::Equals,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::StartedBy,
+        Relation::OverlappedBy,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    /// The relation of the other interval to the first one.
+    pub fn inverse(self) -> Relation {
+        Relation::ALL[12 - self as usize]
+    }
+
+    /// One of the intervals contains the other, as in part 1.
+    pub fn is_containment(self) -> bool {
+        matches!(
+            self,
+            Relation::Starts
+                | Relation::During
+                | Relation::Finishes
+                | Relation::Equals
+                | Relation::FinishedBy
+                | Relation::Contains
+                | Relation::StartedBy
+        )
+    }
+
+    /// The intervals share at least a section, as in part 2.
+    pub fn is_intersecting(self) -> bool {
+        !matches!(
+            self,
+            Relation::Before | Relation::Meets | Relation::MetBy | Relation::After
+        )
+    }
+}
+
+impl Interval {
+    /// `None` if the interval is empty.
+    pub fn new(start: u16, end: u16) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    pub fn end(&self) -> u16 {
+        self.end
+    }
+
+    /// The number of sections.
+    pub fn sections(&self) -> u32 {
+        self.end as u32 - self.start as u32 + 1
+    }
+
+    // the sections as a half-open range, so that meeting intervals share an
+    // end
+    fn bounds(&self) -> (u32, u32) {
+        (self.start as u32, self.end as u32 + 1)
+    }
+
+    pub fn relation(&self, other: &Interval) -> Relation {
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+
+        if end < other_start {
+            Relation::Before
+        } else if end == other_start {
+            Relation::Meets
+        } else if other_end < start {
+            Relation::After
+        } else if other_end == start {
+            Relation::MetBy
+        } else {
+            match (start.cmp(&other_start), end.cmp(&other_end)) {
+                (Ordering::Less, Ordering::Less) => Relation::Overlaps,
+                (Ordering::Less, Ordering::Equal) => Relation::FinishedBy,
+                (Ordering::Less, Ordering::Greater) => Relation::Contains,
+                (Ordering::Equal, Ordering::Less) => Relation::Starts,
+                (Ordering::Equal, Ordering::Equal) => Relation::Equals,
+                (Ordering::Equal, Ordering::Greater) => Relation::StartedBy,
+                (Ordering::Greater, Ordering::Less) => Relation::During,
+                (Ordering::Greater, Ordering::Equal) => Relation::Finishes,
+                (Ordering::Greater, Ordering::Greater) => Relation::OverlappedBy,
+            }
+        }
+    }
+
+    /// Whether every section of `other` is in this interval.
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// The sections in both intervals, `None` if they share none.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The number of sections in both intervals.
+    pub fn overlap_len(&self, other: &Interval) -> u32 {
+        self.intersection(other)
+            .map_or(0, |overlap| overlap.sections())
+    }
+
+    /// The sections in either interval, `None` if they do not make a single
+    /// interval, sections being left between them.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        match self.relation(other) {
+            Relation::Before | Relation::After => None,
+            _ => Interval::new(self.start.min(other.start), self.end.max(other.end)),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Interval, Interval)>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(pairs: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(pairs).into())
+    }
+
+    fn part2(pairs: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(pairs).into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(Interval, Interval)>> {
+    lines(input)
+        .map(|line| {
+            let [first, second] = line
+                .split_n(",", "two ranges separated by `,`")?
+                .map(|range| parse_interval(&line, range));
+            Ok((first?, second?))
+        })
+        .collect()
+}
+
+fn parse_interval(line: &Line, range: &str) -> Result<Interval> {
+    let (start, end) = range
+        .trim()
+        .split_once('-')
+        .ok_or_else(|| line.malformed(range, "a range like `2-4`"))?;
+    Interval::new(line.parse(start)?, line.parse(end)?)
+        .ok_or_else(|| line.invalid(range, "the range is empty"))
+}
+
+/// The number of pairs for each relation of the first interval to the
+/// second one.
+pub fn relation_counts(pairs: &[(Interval, Interval)]) -> BTreeMap<Relation, usize> {
+    let mut counts = BTreeMap::new();
+    for (first, second) in pairs {
+        *counts.entry(first.relation(second)).or_default() += 1;
+    }
+    counts
+}
+
+/// The number of pairs whose relation satisfies `predicate`.
+pub fn count_pairs(pairs: &[(Interval, Interval)], predicate: impl Fn(Relation) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|(first, second)| predicate(first.relation(second)))
+        .count()
+}
+
+pub fn part1(pairs: &[(Interval, Interval)]) -> usize {
+    if log_enabled!(Level::Debug) {
+        debug!("{:?}", relation_counts(pairs));
+    }
+    count_pairs(pairs, Relation::is_containment)
+}
+
+pub fn part2(pairs: &[(Interval, Interval)]) -> usize {
+    if log_enabled!(Level::Debug) {
+        let overlap: u64 = pairs
+            .iter()
+            .map(|(first, second)| first.overlap_len(second) as u64)
+            .sum();
+        debug!("{overlap} sections assigned twice");
+    }
+    count_pairs(pairs, Relation::is_intersecting)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: u16, end: u16) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_relation() {
+        let reference = interval(4, 6);
+        let cases = [
+            (interval(1, 2), Relation::Before),
+            (interval(1, 3), Relation::Meets),
+            (interval(2, 4), Relation::Overlaps),
+            (interval(4, 5), Relation::Starts),
+            (interval(5, 5), Relation::During),
+            (interval(5, 6), Relation::Finishes),
+            (interval(4, 6), Relation::Equals),
+            (interval(3, 6), Relation::FinishedBy),
+            (interval(3, 7), Relation::Contains),
+            (interval(4, 7), Relation::StartedBy),
+            (interval(6, 8), Relation::OverlappedBy),
+            (interval(7, 9), Relation::MetBy),
+            (interval(8, 9), Relation::After),
+        ];
+        for (i, (other, relation)) in cases.into_iter().enumerate() {
+            assert_eq!(relation, Relation::ALL[i]);
+            assert_eq!(other.relation(&reference), relation, "{other:?}");
+            assert_eq!(reference.relation(&other), relation.inverse(), "{other:?}");
+            assert_eq!(
+                relation.is_containment(),
+                other.contains(&reference) || reference.contains(&other)
+            );
+            assert_eq!(
+                relation.is_intersecting(),
+                other.intersection(&reference).is_some()
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let (first, second) = (interval(2, 6), interval(4, 8));
+        assert_eq!(first.intersection(&second), Some(interval(4, 6)));
+        assert_eq!(first.union(&second), Some(interval(2, 8)));
+        assert_eq!(first.overlap_len(&second), 3);
+
+        let (first, second) = (interval(2, 3), interval(4, 5));
+        assert_eq!(first.intersection(&second), None);
+        assert_eq!(first.union(&second), Some(interval(2, 5)));
+        assert_eq!(first.overlap_len(&second), 0);
+
+        assert_eq!(interval(2, 3).union(&interval(5, 5)), None);
+        assert_eq!(interval(0, u16::MAX).sections(), 65536);
+        assert_eq!(Interval::new(3, 2), None);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::parse::{blocks, end_of_input, Line};
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use std::collections::HashMap;
+
+pub type Crates = HashMap<u8, Vec<char>>;
+
+#[derive(Debug)]
+pub struct Move {
+    count: usize,
+    from: u8,
+    to: u8,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Crates, Vec<Move>);
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((crates, moves): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(crates, moves)?.into())
+    }
+
+    fn part2((crates, moves): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(crates, moves)?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Crates, Vec<Move>)> {
+    let blocks = blocks(input);
+    let [crates_input, moves] = blocks.as_slice() else {
+        return Err(Error::malformed(
+            end_of_input(input),
+            "",
+            "the crates and the moves separated by a blank line",
+        ));
+    };
+
+    // the numbers line tells how many stacks there are, they are always in order
+    let (numbers, crates_input) = crates_input.split_last().unwrap();
+    let stack_count = match numbers.text.split_whitespace().last() {
+        Some(last) => numbers.parse::<u8>(last)?,
+        None => return Err(numbers.malformed(numbers.text, "the stack numbers")),
+    };
+
+    let mut crates: Crates = (1..=stack_count).map(|i| (i, vec![])).collect();
+
+    for line in crates_input {
+        let mut count: u8 = 0;
+        let mut chars = line.text.chars();
+
+        while let Some(char) = chars.next() {
+            if char == '[' {
+                let current_crate = chars
+                    .next()
+                    .ok_or_else(|| line.malformed(line.text, "a crate like `[A]`"))?;
+                count += 1;
+
+                let stack_number = ((count as f32) / 4.0).floor() as u8 + 1;
+
+                crates
+                    .get_mut(&stack_number)
+                    .ok_or_else(|| {
+                        line.invalid(line.text, format!("there are only {stack_count} stacks"))
+                    })?
+                    .insert(0, current_crate);
+            }
+            count += 1;
+        }
+    }
+
+    let moves = moves
+        .iter()
+        .map(|line| parse_move(line, stack_count))
+        .collect::<Result<Vec<Move>>>()?;
+
+    Ok((crates, moves))
+}
+
+fn parse_move(line: &Line, stack_count: u8) -> Result<Move> {
+    let [_, count, _, from, _, to] = line.split_n(" ", "`move <n> from <stack> to <stack>`")?;
+    let count = line.parse(count)?;
+
+    let parse_stack = |text: &str| -> Result<u8> {
+        let stack = line.parse(text)?;
+        if !(1..=stack_count).contains(&stack) {
+            return Err(line.invalid(text, format!("there are only {stack_count} stacks")));
+        }
+        Ok(stack)
+    };
+
+    Ok(Move {
+        count,
+        from: parse_stack(from)?,
+        to: parse_stack(to)?,
+    })
+}
+
+fn take_crates(crates: &mut Crates, _move: &Move) -> Result<Vec<char>> {
+    let from_vec = crates.get_mut(&_move.from).unwrap();
+    if from_vec.len() < _move.count {
+        return Err(Error::no_solution(format!(
+            "cannot move {} crates from stack {} holding {}",
+            _move.count,
+            _move.from,
+            from_vec.len()
+        )));
+    }
+    let last_index = from_vec.len() - _move.count;
+    Ok(from_vec.split_off(last_index))
+}
+
+pub fn top_crates(crates: &Crates) -> String {
+    let mut result = String::from("");
+    for i in 1..=crates.keys().len() {
+        let stack = crates.get(&u8::try_from(i).unwrap()).unwrap();
+        if let Some(top) = stack.last() {
+            result.push(*top);
+        }
+    }
+
+    result
+}
+
+pub fn part1(crates: &Crates, moves: &[Move]) -> Result<String> {
+    let mut crates = crates.clone();
+
+    for _move in moves {
+        let mut to_move = take_crates(&mut crates, _move)?;
+        to_move.reverse();
+        crates.get_mut(&_move.to).unwrap().extend(to_move);
+    }
+
+    Ok(top_crates(&crates))
+}
+
+pub fn part2(crates: &Crates, moves: &[Move]) -> Result<String> {
+    let mut crates = crates.clone();
+
+    for _move in moves {
+        let to_move = take_crates(&mut crates, _move)?;
+        crates.get_mut(&_move.to).unwrap().extend(to_move);
+    }
+
+    Ok(top_crates(&crates))
+}
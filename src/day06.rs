@@ -0,0 +1,40 @@
+use crate::parse::single_line;
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let buffer = single_line(input, "a datastream buffer")?;
+        Ok(buffer.text.trim().to_string())
+    }
+
+    fn part1(buffer: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(run(buffer, 4)?.into())
+    }
+
+    fn part2(buffer: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(run(buffer, 14)?.into())
+    }
+}
+
+pub fn run(buffer: &str, marker_size: usize) -> Result<usize> {
+    let buffer: Vec<char> = buffer.chars().collect();
+    let mut index = marker_size - 1;
+    let len = buffer.len();
+    while index < len {
+        let slice = &buffer[index - (marker_size - 1)..=index];
+        let hashset: HashSet<&char> = HashSet::from_iter(slice);
+        if hashset.len() == marker_size {
+            return Ok(index + 1);
+        }
+        index += 1;
+    }
+    Err(Error::no_solution(format!(
+        "no {marker_size} different characters in a row"
+    )))
+}
@@ -0,0 +1,183 @@
+use crate::parse::lines;
+use crate::{Answer, Error, Result, Solution};
+use log::{debug, trace};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct File {
+    size: u32,
+}
+
+#[derive(Debug)]
+pub struct Dir {
+    path: Vec<String>,
+    subdirs: Vec<String>,
+    files: Vec<File>,
+}
+
+impl Dir {
+    fn new(path: Vec<String>) -> Self {
+        Dir {
+            path,
+            subdirs: vec![],
+            files: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Directories up to this size are summed in part 1.
+    pub max_size: u32,
+    /// Size of the disk.
+    pub total_space: u32,
+    /// Free space the update needs.
+    pub needed_space: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            max_size: 100000,
+            total_space: 70000000,
+            needed_space: 30000000,
+        }
+    }
+}
+
+pub type Filesystem = HashMap<String, Dir>;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Filesystem;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(filesystem: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(run(filesystem, options)?.0.into())
+    }
+
+    fn part2(filesystem: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(run(filesystem, options)?.1.into())
+    }
+}
+
+fn format_dir_name(path: &[String]) -> String {
+    format!("/{}", path[1..].join("/"))
+}
+
+pub fn parse_input(input: &str) -> Result<Filesystem> {
+    let mut input = lines(input).peekable();
+
+    let mut cwd: Vec<String> = vec![];
+    let mut filesystem: Filesystem = HashMap::new();
+
+    while let Some(line) = input.next() {
+        let command = line
+            .text
+            .trim()
+            .strip_prefix("$ ")
+            .ok_or_else(|| line.malformed(line.text.trim(), "a command starting with `$`"))?;
+
+        if let Some(to_dir) = command.strip_prefix("cd ") {
+            if to_dir == ".." {
+                if cwd.pop().is_none() {
+                    return Err(line.invalid(to_dir, "already at the root"));
+                }
+            } else {
+                cwd.push(to_dir.to_string());
+            }
+        } else if command == "ls" {
+            if cwd.is_empty() {
+                return Err(line.invalid(command, "no current directory"));
+            }
+            let mut current_dir = Dir::new(cwd.clone());
+            while let Some(next_line) = input.next_if(|line| !line.text.trim().starts_with('$')) {
+                let [data, name] = next_line.split_n(" ", "`dir <name>` or `<size> <name>`")?;
+
+                if data == "dir" {
+                    current_dir.subdirs.push(name.to_string());
+                } else {
+                    let size = next_line.parse::<u32>(data)?;
+                    current_dir.files.push(File { size });
+                }
+            }
+            filesystem.insert(format_dir_name(&cwd), current_dir);
+        } else {
+            return Err(line.malformed(command, "`cd <dir>` or `ls`"));
+        }
+    }
+
+    Ok(filesystem)
+}
+
+pub fn run(filesystem: &Filesystem, options: &Options) -> Result<(u32, u32)> {
+    let mut sizes: HashMap<String, u32> = HashMap::new();
+
+    for dir in filesystem.values() {
+        let dir_size: u32 = compute_dir_size(dir, filesystem)?;
+        sizes.insert(format_dir_name(&dir.path), dir_size);
+    }
+
+    debug!("sizes: {sizes:?}");
+    let result_part1 = sizes
+        .values()
+        .filter(|size| **size < options.max_size)
+        .try_fold(0u32, |sum, size| sum.checked_add(*size))
+        .ok_or_else(|| Error::overflow("the sum of the small directories"))?;
+
+    let used = sizes
+        .get("/")
+        .ok_or_else(|| Error::no_solution("the root directory was never listed"))?;
+    let free = options.total_space.checked_sub(*used).ok_or_else(|| {
+        Error::no_solution(format!(
+            "{used} bytes are used on a disk of {}",
+            options.total_space
+        ))
+    })?;
+    // nothing to delete when there is already enough space
+    let to_free = options.needed_space.saturating_sub(free);
+
+    debug!("to_free: {to_free}");
+
+    let mut sorted_sizes: Vec<(&String, &u32)> = sizes.iter().collect();
+    sorted_sizes.sort_by(|a, b| a.1.cmp(b.1));
+    trace!("sorted sizes: {sorted_sizes:#?}");
+    for size in sorted_sizes {
+        if *size.1 >= to_free {
+            return Ok((result_part1, *size.1));
+        }
+    }
+    Err(Error::no_solution(
+        "no directory is big enough to free the space",
+    ))
+}
+
+pub fn compute_dir_size(dir: &Dir, filesystem: &Filesystem) -> Result<u32> {
+    let overflow = || Error::overflow(format!("the size of `{}`", format_dir_name(&dir.path)));
+    let mut total_size = dir
+        .files
+        .iter()
+        .try_fold(0u32, |sum, file| sum.checked_add(file.size))
+        .ok_or_else(overflow)?;
+
+    let path = dir.path.clone();
+    for subdir in dir.subdirs.clone() {
+        let subdir_name = format_dir_name(&[path.clone(), vec![subdir.clone()]].concat());
+        let subdir = filesystem.get(&subdir_name).ok_or_else(|| {
+            Error::no_solution(format!("directory `{subdir_name}` was never listed"))
+        })?;
+
+        total_size = total_size
+            .checked_add(compute_dir_size(subdir, filesystem)?)
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(total_size)
+}
@@ -0,0 +1,118 @@
+use crate::{Answer, Grid, NoOptions, Result, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>> {
+    Grid::parse(input, |_, c| c.to_digit(10), "a digit")
+}
+
+pub fn part1(input: &Grid<u32>) -> u32 {
+    let mut total_visible = 0;
+
+    for i in 0..input.height() {
+        for j in 0..input.width() {
+            let line = input.row(i);
+            let column: Vec<u32> = input.column(j).copied().collect();
+
+            let tree_height = input[(j, i)];
+            let visible = is_visible(tree_height, line, &column, j, i);
+
+            if visible {
+                total_visible += 1;
+            }
+        }
+    }
+
+    total_visible
+}
+
+pub fn is_visible(
+    tree_height: u32,
+    line: &[u32],
+    column: &[u32],
+    line_index: usize,
+    column_index: usize,
+) -> bool {
+    if line_index == 0 || line_index == line.len() - 1 {
+        return true;
+    }
+    if column_index == 0 || column_index == column.len() - 1 {
+        return true;
+    }
+
+    let left = line[..line_index].iter().max().unwrap();
+    let right = line[line_index + 1..].iter().max().unwrap();
+    let top = column[..column_index].iter().max().unwrap();
+    let down = column[column_index + 1..].iter().max().unwrap();
+
+    if tree_height > *left || tree_height > *right || tree_height > *top || tree_height > *down {
+        return true;
+    }
+
+    false
+}
+
+pub fn part2(input: &Grid<u32>) -> u32 {
+    let mut max_scenic_score = 0;
+
+    for i in 0..input.height() {
+        for j in 0..input.width() {
+            let line = input.row(i);
+            let column: Vec<u32> = input.column(j).copied().collect();
+
+            let tree_height = input[(j, i)];
+            let score = scenic_score(tree_height, line, &column, j, i);
+
+            if score > max_scenic_score {
+                max_scenic_score = score;
+            }
+        }
+    }
+
+    max_scenic_score
+}
+
+pub fn scenic_score(
+    tree_height: u32,
+    line: &[u32],
+    column: &[u32],
+    line_index: usize,
+    column_index: usize,
+) -> u32 {
+    let left: Vec<u32> = line[..line_index].iter().copied().rev().collect();
+    let right = line[line_index + 1..].to_vec();
+    let top: Vec<u32> = column[..column_index].iter().copied().rev().collect();
+    let down = column[column_index + 1..].to_vec();
+
+    let mut scores: Vec<u32> = vec![];
+
+    for direction in [left, right, top, down] {
+        let mut score = 0;
+        for i in direction {
+            score += 1;
+            if i >= tree_height {
+                break;
+            }
+        }
+        scores.push(score)
+    }
+
+    scores.iter().product()
+}
@@ -1,36 +1,44 @@
 use std::collections::HashSet;
 
-use adventofcode::read_file;
+use crate::parse::lines;
+use crate::{Answer, NoOptions, Result, Solution};
 
 #[derive(Debug)]
-struct Action(char, i32);
+pub struct Action(char, i32);
 
-fn main() -> Result<(), String> {
-    let file = read_file("09").expect("cant read file");
+pub struct Day09;
 
-    let actions: Vec<Action> = parse_input(&file);
+impl Solution for Day09 {
+    type Input = Vec<Action>;
+    type Options = NoOptions;
 
-    let result_part1 = part1(&actions)?;
-    println!("Part1: {result_part1}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
 
-    let result_part2 = part2(&actions)?;
-    println!("Part2: {result_part2}");
+    fn part1(actions: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(actions).into())
+    }
 
-    Ok(())
+    fn part2(actions: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(actions).into())
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Action> {
-    input
-        .trim()
-        .split('\n')
-        .map(|e| {
-            let split = e.split(' ').collect::<Vec<&str>>();
-            Action(split[0].chars().next().unwrap(), split[1].parse().unwrap())
+pub fn parse_input(input: &str) -> Result<Vec<Action>> {
+    lines(input)
+        .map(|line| {
+            let [direction, steps] = line.split_n(" ", "`<direction> <steps>`")?;
+            let direction = match direction {
+                "R" | "L" | "U" | "D" => direction.chars().next().unwrap(),
+                _ => return Err(line.malformed(direction, "`R`, `L`, `U` or `D`")),
+            };
+            Ok(Action(direction, line.parse(steps)?))
         })
         .collect()
 }
 
-fn part1(actions: &Vec<Action>) -> Result<usize, String> {
+pub fn part1(actions: &[Action]) -> usize {
     let mut visited_positions: HashSet<(i32, i32)> = HashSet::new();
 
     let mut head_position: (i32, i32) = (0, 0);
@@ -44,7 +52,7 @@ fn part1(actions: &Vec<Action>) -> Result<usize, String> {
                 'L' => head_position.0 -= 1,
                 'U' => head_position.1 += 1,
                 'D' => head_position.1 -= 1,
-                _ => return Err("not the right direction".to_string()),
+                _ => unreachable!("checked by `parse_input`"),
             }
             i += 1;
 
@@ -68,10 +76,10 @@ fn part1(actions: &Vec<Action>) -> Result<usize, String> {
         }
     }
 
-    Ok(visited_positions.len())
+    visited_positions.len()
 }
 
-fn part2(actions: &Vec<Action>) -> Result<usize, String> {
+pub fn part2(actions: &[Action]) -> usize {
     let mut visited_positions: HashSet<(i32, i32)> = HashSet::new();
 
     let mut head_position: (i32, i32) = (0, 0);
@@ -85,7 +93,7 @@ fn part2(actions: &Vec<Action>) -> Result<usize, String> {
                 'L' => head_position.0 -= 1,
                 'U' => head_position.1 += 1,
                 'D' => head_position.1 -= 1,
-                _ => return Err("not the right direction".to_string()),
+                _ => unreachable!("checked by `parse_input`"),
             }
             i += 1;
 
@@ -121,46 +129,5 @@ fn part2(actions: &Vec<Action>) -> Result<usize, String> {
         }
     }
 
-    Ok(visited_positions.len())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const example_input: &str = "
-R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2
-";
-
-    const example_input2: &str = "
-R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-";
-
-    #[test]
-    fn test_part1() {
-        let actions: Vec<Action> = parse_input(example_input);
-        let result = part1(&actions).unwrap();
-        assert_eq!(result, 13);
-    }
-
-    #[test]
-    fn test_part2() {
-        let actions: Vec<Action> = parse_input(example_input2);
-        let result = part2(&actions).unwrap();
-        assert_eq!(result, 36);
-    }
+    visited_positions.len()
 }
@@ -0,0 +1,127 @@
+use crate::parse::lines;
+use crate::{Answer, NoOptions, Result, Solution};
+
+#[derive(Debug)]
+pub enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+#[derive(Debug)]
+struct State {
+    x_register: i32,
+    cycle: i32,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(instructions).into())
+    }
+
+    fn part2(instructions: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        let drawing = part2(instructions);
+        Ok(drawing
+            .chars()
+            .collect::<Vec<char>>()
+            .chunks(40)
+            .map(|c| c.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+            .into())
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    lines(input)
+        .map(|line| {
+            let instruction = line.text.trim();
+            if instruction == "noop" {
+                Ok(Instruction::Noop)
+            } else if let Some(value) = instruction.strip_prefix("addx ") {
+                Ok(Instruction::AddX(line.parse(value)?))
+            } else {
+                Err(line.malformed(instruction, "`addx <value>` or `noop`"))
+            }
+        })
+        .collect()
+}
+
+pub fn part1(instructions: &[Instruction]) -> i32 {
+    let mut state: State = State {
+        x_register: 1,
+        cycle: 0,
+    };
+
+    let mut signal_strengths = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::AddX(value) => {
+                state.cycle += 1;
+                signal_strengths += check_state(&state);
+                state.cycle += 1;
+                signal_strengths += check_state(&state);
+                state.x_register += value;
+            }
+            Instruction::Noop => {
+                state.cycle += 1;
+                signal_strengths += check_state(&state);
+            }
+        };
+    }
+
+    signal_strengths
+}
+
+fn check_state(state: &State) -> i32 {
+    if state.cycle % 40 == 20 {
+        state.cycle * state.x_register
+    } else {
+        0
+    }
+}
+
+pub fn part2(instructions: &[Instruction]) -> String {
+    let mut state: State = State {
+        x_register: 1,
+        cycle: 0,
+    };
+
+    let mut drawing = String::from("");
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::AddX(value) => {
+                drawing.push(check_sprite(&state));
+                state.cycle += 1;
+                drawing.push(check_sprite(&state));
+                state.cycle += 1;
+                state.x_register += value;
+            }
+            Instruction::Noop => {
+                drawing.push(check_sprite(&state));
+                state.cycle += 1;
+            }
+        };
+    }
+
+    drawing
+}
+
+fn check_sprite(state: &State) -> char {
+    let sprite = [state.x_register - 1, state.x_register, state.x_register + 1];
+    if sprite.contains(&(state.cycle % 40)) {
+        '#'
+    } else {
+        '.'
+    }
+}
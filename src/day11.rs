@@ -0,0 +1,192 @@
+use crate::parse::{blocks, Line};
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use log::{debug, trace};
+use std::fmt;
+
+pub struct Monkey {
+    n: u8,
+    items: Vec<u64>,
+    /// `None` when the new worry level overflows.
+    operation: Box<dyn Fn(u64) -> Option<u64>>,
+    test: Box<dyn Fn(u64) -> u8>,
+    n_inspect_items: u64,
+}
+
+impl fmt::Debug for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Monkey {} : {:?} (inspections: {})",
+            self.n, self.items, self.n_inspect_items
+        )
+    }
+}
+
+pub struct Day11;
+
+// monkeys hold boxed closures and are mutated while playing, so each part
+// parses its own copy from the raw notes
+impl Solution for Day11 {
+    type Input = String;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(notes: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        let (mut monkeys, _) = parse_input(notes)?;
+        Ok(part1(&mut monkeys)?.into())
+    }
+
+    fn part2(notes: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        let (mut monkeys, lcm) = parse_input(notes)?;
+        Ok(part2(&mut monkeys, lcm)?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Monkey>, u64)> {
+    let blocks = blocks(input);
+
+    let mut monkeys: Vec<Monkey> = vec![];
+    let mut lcm: u64 = 1;
+
+    for monkey_info in &blocks {
+        let [header, items, operation, divisible, if_true, if_false] = monkey_info.as_slice()
+        else {
+            let last = monkey_info.last().unwrap();
+            return Err(last.malformed(last.text, "the 6 lines describing a monkey"));
+        };
+
+        let n: u8 = header.parse(header.strip_prefix("Monkey ")?.trim_end_matches(':'))?;
+        let items: Vec<u64> = items
+            .strip_prefix("Starting items:")?
+            .split(',')
+            .map(|i| items.parse(i.trim()))
+            .collect::<Result<_>>()?;
+
+        let [operator, operand] = operation
+            .strip_prefix("Operation: new = old ")?
+            .split(' ')
+            .collect::<Vec<&str>>()
+            .try_into()
+            .map_err(|_| operation.malformed(operation.text.trim(), "`new = old <op> <value>`"))?;
+        let operator = match operator {
+            "*" | "+" => operator.chars().next().unwrap(),
+            _ => return Err(operation.malformed(operator, "`*` or `+`")),
+        };
+        let operand: Option<u64> = match operand {
+            "old" => None,
+            _ => Some(operation.parse(operand)?),
+        };
+
+        let divisor = divisible.strip_prefix("Test: divisible by ")?;
+        let test: u64 = divisible.parse(divisor)?;
+        if test == 0 {
+            return Err(divisible.invalid(divisor, "cannot divide by 0"));
+        }
+
+        let throw_to = |line: &Line, prefix: &str| -> Result<u8> {
+            let target = line.strip_prefix(prefix)?;
+            let n: u8 = line.parse(target)?;
+            if n as usize >= blocks.len() {
+                return Err(
+                    line.invalid(target, format!("there are only {} monkeys", blocks.len()))
+                );
+            }
+            Ok(n)
+        };
+        let test_true = throw_to(if_true, "If true: throw to monkey ")?;
+        let test_false = throw_to(if_false, "If false: throw to monkey ")?;
+
+        lcm = lcm
+            .checked_mul(test)
+            .ok_or_else(|| Error::overflow("the product of the divisors"))?;
+
+        monkeys.push(Monkey {
+            n,
+            items,
+            operation: Box::new(move |a| {
+                let operand = operand.unwrap_or(a);
+                match operator {
+                    '*' => a.checked_mul(operand),
+                    _ => a.checked_add(operand),
+                }
+            }),
+            test: Box::new(move |a| if a % test == 0 { test_true } else { test_false }),
+            n_inspect_items: 0,
+        });
+    }
+
+    Ok((monkeys, lcm))
+}
+
+pub fn monkey_business(monkeys: &[Monkey]) -> Result<u64> {
+    let mut inspected_items = monkeys
+        .iter()
+        .map(|m| m.n_inspect_items)
+        .collect::<Vec<u64>>();
+    inspected_items.sort_by(|a, b| b.cmp(a));
+
+    match inspected_items[..] {
+        [first, second, ..] => first
+            .checked_mul(second)
+            .ok_or_else(|| Error::overflow("the monkey business")),
+        _ => Err(Error::no_solution("there must be at least 2 monkeys")),
+    }
+}
+
+/// The worry level of `item` once `monkey` inspected it.
+fn inspect(monkey: &Monkey, item: u64) -> Result<u64> {
+    (monkey.operation)(item).ok_or_else(|| {
+        Error::overflow(format!(
+            "the worry level {item} inspected by monkey {}",
+            monkey.n
+        ))
+    })
+}
+
+pub fn part1(monkeys: &mut [Monkey]) -> Result<u64> {
+    for _ in 0..20 {
+        for n in 0..monkeys.len() {
+            while !monkeys[n].items.is_empty() {
+                monkeys[n].n_inspect_items += 1;
+
+                let item = monkeys[n].items[0];
+                monkeys[n].items = monkeys[n].items[1..].to_vec();
+
+                let worry = inspect(&monkeys[n], item)? / 3;
+                let to_monkey = (monkeys[n].test)(worry);
+                monkeys[to_monkey as usize].items.push(worry);
+
+                trace!("{item} -> {worry} from {} to {to_monkey}", monkeys[n].n);
+            }
+        }
+    }
+
+    debug!("{monkeys:#?}");
+    monkey_business(monkeys)
+}
+
+pub fn part2(monkeys: &mut [Monkey], lcm: u64) -> Result<u64> {
+    for _ in 0..10000 {
+        for n in 0..monkeys.len() {
+            while !monkeys[n].items.is_empty() {
+                monkeys[n].n_inspect_items += 1;
+
+                let item = monkeys[n].items[0];
+                monkeys[n].items = monkeys[n].items[1..].to_vec();
+
+                let worry = inspect(&monkeys[n], item)? % lcm;
+                let to_monkey = (monkeys[n].test)(worry);
+                monkeys[to_monkey as usize].items.push(worry);
+
+                trace!("{item} -> {worry} from {} to {to_monkey}", monkeys[n].n);
+            }
+        }
+    }
+
+    debug!("{monkeys:#?}");
+    monkey_business(monkeys)
+}
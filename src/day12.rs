@@ -0,0 +1,138 @@
+use crate::parse::end_of_input;
+use crate::{Answer, Error, Grid, NoOptions, Point2, Result, Search, Solution};
+use log::debug;
+
+pub type Position = Point2<usize>;
+
+pub type Map = Grid<u32>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ((Map, Position, Position), (Map, Vec<Position>, Position));
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_input(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1(((map, start, end), _): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(map, start, end)?.into())
+    }
+
+    fn part2((_, (map, starts, end)): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(map, starts, end)?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Map, Position, Position)> {
+    let mut start = None;
+    let mut end = None;
+
+    let map = Grid::parse(
+        input,
+        |(x, y), c| match c {
+            'S' => {
+                start = Some(Position::new(x, y));
+                Some(1)
+            }
+            'E' => {
+                end = Some(Position::new(x, y));
+                Some(26)
+            }
+            'a'..='z' => Some(c as u32 - 96),
+            _ => None,
+        },
+        "a height between `a` and `z`, `S` or `E`",
+    )?;
+
+    let start = start.ok_or_else(|| Error::malformed(end_of_input(input), "", "a start `S`"))?;
+    let end = end.ok_or_else(|| Error::malformed(end_of_input(input), "", "an end `E`"))?;
+    Ok((map, start, end))
+}
+
+// every `a` is a possible start, as well as `S`
+pub fn parse_input_part2(input: &str) -> Result<(Map, Vec<Position>, Position)> {
+    let (map, _, end) = parse_input(input)?;
+    let starts = map
+        .iter()
+        .filter(|(_, height)| **height == 1)
+        .map(|((x, y), _)| Position::new(x, y))
+        .collect();
+    Ok((map, starts, end))
+}
+
+// the neighbours at most one higher
+fn next_positions(input: &Map, current_position: &Position) -> Vec<Position> {
+    let value = input[*current_position];
+
+    input
+        .neighbours4((*current_position).into())
+        .filter(|&neighbour| input[neighbour] <= value + 1)
+        .map(|(x, y)| Position::new(x, y))
+        .collect()
+}
+
+/// Alternative to [`part1`], finds a path but not the shortest one. `None`
+/// if there is none.
+pub fn part1_backtrack(input: &Map, start: &Position, end: &Position) -> Option<usize> {
+    let (found, stats) =
+        Search::new(*start, |p: &Position| next_positions(input, p)).dfs(|p| p == end);
+    debug!("dfs: {stats}");
+    found.map(|found| found.cost)
+}
+
+pub fn part1(input: &Map, start: &Position, end: &Position) -> Result<usize> {
+    let (found, stats) =
+        Search::new(*start, |p: &Position| next_positions(input, p)).bfs(|p| p == end);
+    debug!("bfs: {:?}, {stats}", found.as_ref().map(|found| found.cost));
+
+    found
+        .map(|found| found.cost)
+        .ok_or_else(|| Error::no_solution("no path from the start to the end"))
+}
+
+pub fn part2(input: &Map, starts: &[Position], end: &Position) -> Result<usize> {
+    starts
+        .iter()
+        .filter_map(|start| {
+            let search = Search::new(*start, |p: &Position| next_positions(input, p));
+            search.bfs(|p| p == end).0.map(|found| found.cost)
+        })
+        .min()
+        .ok_or_else(|| Error::no_solution("no path from any `a` to the end"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/day12/example1.txt");
+    const EXAMPLE2: &str = include_str!("../fixtures/day12/example2.txt");
+
+    #[test]
+    fn test_searches() {
+        // every step costs 1, the weighted searches find the shortest path too
+        let (input, start, end) = parse_input(EXAMPLE1).unwrap();
+        let weighted = |p: &Position| -> Vec<(Position, u32)> {
+            next_positions(&input, p)
+                .into_iter()
+                .map(|p| (p, 1))
+                .collect()
+        };
+
+        let (dijkstra, _) = Search::new(start, weighted).dijkstra(|p| *p == end);
+        let (astar, _) =
+            Search::new(start, weighted).astar(|p| p.manhattan(&end) as u32, |p| *p == end);
+        assert_eq!(dijkstra.unwrap().cost, 31);
+        assert_eq!(astar.unwrap().cost, 31);
+        assert_eq!(part1(&input, &start, &end).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_part1_backtrack() {
+        let (input, start, end) = parse_input(EXAMPLE2).unwrap();
+        let result = part1_backtrack(&input, &start, &end);
+        assert_eq!(result, Some(27));
+    }
+}
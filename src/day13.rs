@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+
+use crate::parse::{blocks, lines, Line};
+use crate::{Answer, NoOptions, Result, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Number(u8),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn add_to_list(&mut self, to_add: Packet) {
+        if let Packet::List(list) = self {
+            list.push(to_add);
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<[Packet; 2]>, Vec<Packet>);
+    type Options = NoOptions;
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_input_part1(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1((pairs, _): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(pairs).into())
+    }
+
+    fn part2((_, packets): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(packets).into())
+    }
+}
+
+pub fn parse_input_part1(input: &str) -> Result<Vec<[Packet; 2]>> {
+    blocks(input)
+        .iter()
+        .map(|pair| match pair.as_slice() {
+            [left, right] => Ok([parse_packet(left)?, parse_packet(right)?]),
+            [.., last] => Err(last.malformed(last.text, "a pair of packets")),
+            [] => unreachable!(),
+        })
+        .collect()
+}
+
+pub fn parse_input_part2(input: &str) -> Result<Vec<Packet>> {
+    lines(input).map(|line| parse_packet(&line)).collect()
+}
+
+fn parse_packet(line: &Line) -> Result<Packet> {
+    let packet = line.text.trim();
+    if !packet.starts_with('[') {
+        return Err(line.malformed(packet, "a list starting with `[`"));
+    }
+
+    let mut index = 0;
+    let result = parse_list(line, packet, &mut index)?;
+    if index + 1 < packet.len() {
+        return Err(line.malformed(&packet[index + 1..], "the end of the packet"));
+    }
+    Ok(result)
+}
+
+// packets are ASCII, so `list` is indexed by bytes
+fn parse_list(line: &Line, list: &str, index: &mut usize) -> Result<Packet> {
+    let mut current_list = Packet::List(vec![]);
+    *index += 1;
+
+    let mut number_start = None;
+
+    loop {
+        let Some(&c) = list.as_bytes().get(*index) else {
+            return Err(line.malformed(&list[list.len()..], "`]`"));
+        };
+        match c {
+            b'[' => {
+                current_list.add_to_list(parse_list(line, list, index)?);
+            }
+            b']' | b',' => {
+                if let Some(start) = number_start.take() {
+                    current_list.add_to_list(Packet::Number(line.parse(&list[start..*index])?));
+                }
+                if c == b']' {
+                    return Ok(current_list);
+                }
+            }
+            b'0'..=b'9' => {
+                number_start.get_or_insert(*index);
+            }
+            _ => return Err(line.malformed(&list[*index..*index + 1], "a number, `[`, `]` or `,`")),
+        };
+        *index += 1;
+    }
+}
+
+/// The order of two packets, `Less` if they are in the right order.
+pub fn compare_packets(left_packet: &Packet, right_packet: &Packet) -> Ordering {
+    match (left_packet, right_packet) {
+        (Packet::Number(left), Packet::Number(right)) => left.cmp(right),
+        (Packet::Number(left), Packet::List(_)) => {
+            let as_list = Packet::List(vec![Packet::Number(*left)]);
+            compare_packets(&as_list, right_packet)
+        }
+        (Packet::List(left), Packet::List(right)) => {
+            for (i, item) in left.iter().enumerate() {
+                if i < right.len() {
+                    let comparison = compare_packets(item, &right[i]);
+                    if comparison != Ordering::Equal {
+                        return comparison;
+                    }
+                } else {
+                    return Ordering::Greater;
+                }
+            }
+            left.len().cmp(&right.len())
+        }
+        (Packet::List(_), Packet::Number(right)) => {
+            let as_list = Packet::List(vec![Packet::Number(*right)]);
+            compare_packets(left_packet, &as_list)
+        }
+    }
+}
+
+pub fn part1(pairs: &[[Packet; 2]]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, [left, right])| compare_packets(left, right) == Ordering::Less)
+        .fold(0, |sum, (i, _)| sum + i + 1)
+}
+
+pub fn part2(packets: &[Packet]) -> usize {
+    let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Number(n)])]);
+    let divider1 = divider(2);
+    let divider2 = divider(6);
+
+    let mut sorted_packets = packets.to_vec();
+    sorted_packets.push(divider1.clone());
+    sorted_packets.push(divider2.clone());
+    sorted_packets.sort_by(compare_packets);
+
+    let mut result = 1;
+    for (i, packet) in sorted_packets.iter().enumerate() {
+        if packet.eq(&divider1) || packet.eq(&divider2) {
+            result *= i + 1;
+        }
+    }
+    result
+}
@@ -0,0 +1,230 @@
+use crate::parse::lines;
+use crate::{
+    Answer, BoundingBox, Cell, Direction8, Frame, NoOptions, Part, Point2, Recorder, Result, Rgb,
+    Solution,
+};
+use std::collections::HashSet;
+
+pub type Position = Point2<u32>;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (HashSet<Position>, u32);
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let positions: Vec<Vec<Position>> = parse_to_positions(input)?;
+        Ok(get_blocked_positions(&positions))
+    }
+
+    fn part1((blocked_positions, lowest_y): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(blocked_positions, *lowest_y).into())
+    }
+
+    fn part2((blocked_positions, lowest_y): &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(blocked_positions, *lowest_y).into())
+    }
+
+    fn visualize(
+        (rocks, lowest_y): &Self::Input,
+        _: &Self::Options,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        let has_floor = part == Part::Two;
+        let cave = Cave::new(rocks, *lowest_y, has_floor);
+        let mut sand = 0;
+        let answer = pour(rocks, *lowest_y, has_floor, |blocked_positions, last| {
+            sand += 1;
+            recorder.step(|| cave.frame(blocked_positions, last, sand));
+        });
+        Some(Ok(answer.into()))
+    }
+}
+
+pub fn parse_to_positions(input: &str) -> Result<Vec<Vec<Position>>> {
+    lines(input)
+        .map(|line| {
+            let mut path: Vec<Position> = vec![];
+            for position in line.text.trim().split(" -> ") {
+                let (x, y) = position
+                    .split_once(',')
+                    .ok_or_else(|| line.malformed(position, "a position like `498,4`"))?;
+                let current = Position::new(line.parse(x)?, line.parse(y)?);
+
+                if let Some(previous) = path.last() {
+                    if previous.x != current.x && previous.y != current.y {
+                        return Err(line.invalid(position, "rock paths cannot be diagonal"));
+                    }
+                }
+                path.push(current);
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+pub fn get_blocked_positions(positions: &[Vec<Position>]) -> (HashSet<Position>, u32) {
+    let mut blocked: HashSet<Position> = HashSet::new();
+    let mut lowest_y = 0;
+
+    for lines in positions {
+        let mut i = 0;
+        loop {
+            if i + 1 >= lines.len() {
+                break;
+            }
+            let start = &lines[i];
+            let end = &lines[i + 1];
+
+            if start.x == end.x {
+                for y in std::cmp::min(start.y, end.y)..=std::cmp::max(start.y, end.y) {
+                    blocked.insert(Position::new(start.x, y));
+                    if y > lowest_y {
+                        lowest_y = y;
+                    }
+                }
+            } else {
+                for x in std::cmp::min(start.x, end.x)..=std::cmp::max(start.x, end.x) {
+                    blocked.insert(Position::new(x, start.y));
+                    if start.y > lowest_y {
+                        lowest_y = start.y;
+                    }
+                }
+            }
+
+            i += 1;
+        }
+    }
+
+    (blocked, lowest_y)
+}
+
+// down first, then down-left, then down-right
+fn fall(blocked_positions: &HashSet<Position>, sand: &Position) -> Option<Position> {
+    [
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::SouthEast,
+    ]
+    .into_iter()
+    .filter_map(|direction| sand.step(direction))
+    .find(|next| !blocked_positions.contains(next))
+}
+
+pub fn part1(blocked_positions: &HashSet<Position>, lowest_y: u32) -> u32 {
+    pour(blocked_positions, lowest_y, false, |_, _| {})
+}
+
+pub fn part2(blocked_positions: &HashSet<Position>, lowest_y: u32) -> u32 {
+    pour(blocked_positions, lowest_y, true, |_, _| {})
+}
+
+// sand falls from the source until it flows into the abyss below the lowest
+// rock or, with the floor, until it piles up to the source. `on_rest` gets the
+// blocked positions every time a unit of sand comes to rest.
+fn pour(
+    rocks: &HashSet<Position>,
+    lowest_y: u32,
+    has_floor: bool,
+    mut on_rest: impl FnMut(&HashSet<Position>, Position),
+) -> u32 {
+    let mut blocked_positions = rocks.clone();
+    let mut sand_deposited: u32 = 0;
+    let floor = lowest_y + 2;
+
+    let start = Position::new(500, 0);
+
+    // the sand can also pile up to its source when the rocks make a bowl
+    while !blocked_positions.contains(&start) {
+        let mut sand_current = start;
+
+        while let Some(next) = fall(&blocked_positions, &sand_current) {
+            if has_floor && next.y == floor {
+                break;
+            }
+            if !has_floor && next.y > lowest_y {
+                return sand_deposited;
+            }
+            sand_current = next;
+        }
+
+        blocked_positions.insert(sand_current);
+        sand_deposited += 1;
+        on_rest(&blocked_positions, sand_current);
+    }
+
+    sand_deposited
+}
+
+const AIR: Cell = Cell::new('.', Rgb(24, 24, 32));
+const ROCK: Cell = Cell::new('#', Rgb(120, 110, 100));
+const SAND: Cell = Cell::new('o', Rgb(220, 180, 90));
+const LAST_SAND: Cell = Cell::new('o', Rgb(255, 245, 200));
+const SOURCE: Cell = Cell::new('+', Rgb(255, 255, 255));
+
+/// What the frames show: the rocks, and the floor of part 2.
+struct Cave<'a> {
+    rocks: &'a HashSet<Position>,
+    floor: Option<u32>,
+    bounds: BoundingBox<Position>,
+}
+
+impl<'a> Cave<'a> {
+    // the rocks and the part of the cave the sand can reach, the floor
+    // included
+    fn new(rocks: &'a HashSet<Position>, lowest_y: u32, has_floor: bool) -> Self {
+        let source = Position::new(500, 0);
+        let floor = has_floor.then_some(lowest_y + 2);
+        let mut bounds = BoundingBox::from_points(rocks).map_or(
+            BoundingBox {
+                min: source,
+                max: source,
+            },
+            |bounds| bounds.including(&source),
+        );
+        if let Some(floor) = floor {
+            bounds = bounds
+                .including(&Position::new(500 - floor.min(500), floor))
+                .including(&Position::new(500 + floor, floor));
+        }
+        Cave {
+            rocks,
+            floor,
+            bounds,
+        }
+    }
+
+    fn frame(&self, blocked_positions: &HashSet<Position>, last: Position, sand: u32) -> Frame {
+        let bounds = &self.bounds;
+        let mut frame = Frame::new(
+            format!("{sand} units of sand at rest"),
+            bounds.width() as usize,
+            bounds.height() as usize,
+            AIR,
+        );
+        let cell = |position: &Position| {
+            (
+                (position.x - bounds.min.x) as usize,
+                (position.y - bounds.min.y) as usize,
+            )
+        };
+
+        for position in blocked_positions {
+            let tile = match self.rocks.contains(position) {
+                true => ROCK,
+                false => SAND,
+            };
+            frame.draw(cell(position), tile);
+        }
+        if let Some(floor) = self.floor {
+            for x in bounds.min.x..=bounds.max.x {
+                frame.draw(cell(&Position::new(x, floor)), ROCK);
+            }
+        }
+        frame.draw(cell(&Position::new(500, 0)), SOURCE);
+        frame.draw(cell(&last), LAST_SAND);
+        frame
+    }
+}
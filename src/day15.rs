@@ -0,0 +1,248 @@
+use crate::parse::{lines, Pattern};
+use crate::{Answer, Error, Point2, Result, Solution};
+use log::debug;
+use serde::Deserialize;
+use std::{collections::HashSet, ops::RangeInclusive};
+
+pub type Position = Point2<i64>;
+
+#[derive(Debug)]
+pub struct Pair {
+    sensor: Position,
+    beacon: Position,
+    distance: i64,
+}
+
+#[derive(Debug)]
+pub struct Limits {
+    pub x_min: i64,
+    pub x_max: i64,
+    pub y_min: i64,
+    pub y_max: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MyRange(pub RangeInclusive<i64>);
+impl MyRange {
+    pub fn is_overlapping(&self, other: &Self) -> bool {
+        self.0.contains(other.0.start()) || self.0.contains(other.0.end())
+    }
+
+    /// The union of both ranges, `None` if they do not overlap.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        if self.is_overlapping(other) {
+            Some(MyRange(
+                std::cmp::min(*self.0.start(), *other.0.start())
+                    ..=std::cmp::max(*self.0.end(), *other.0.end()),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Row whose covered positions are counted in part 1.
+    pub row: i64,
+    /// The distress beacon is searched in `0..=search_max` on both axes.
+    pub search_max: i64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            row: 2000000,
+            search_max: 4000000,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Pair>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(pairs: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part1(pairs, options.row).into())
+    }
+
+    fn part2(pairs: &Self::Input, options: &Options) -> Result<Answer> {
+        let limits = Limits {
+            x_min: 0,
+            x_max: options.search_max,
+            y_min: 0,
+            y_max: options.search_max,
+        };
+        part2(pairs, &limits)
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("no position left for the distress beacon"))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Pair>> {
+    let pattern = Pattern::new("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}");
+    lines(input)
+        .map(|line| {
+            let fields = pattern.matches(&line)?;
+
+            let sensor = Position::new(fields.get("sx")?, fields.get("sy")?);
+            let beacon = Position::new(fields.get("bx")?, fields.get("by")?);
+
+            Ok(Pair {
+                sensor,
+                beacon,
+                distance: sensor.manhattan(&beacon),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(pairs: &[Pair], line_to_check: i64) -> usize {
+    let total_coverage = coverage(pairs, line_to_check, i64::MIN, i64::MAX);
+
+    let total_values = total_coverage
+        .iter()
+        .fold(0, |acc, range| acc + (range.0.end() + 1 - range.0.start()));
+
+    let beacons_positions_to_remove: HashSet<i64> = pairs
+        .iter()
+        .filter_map(|pair| {
+            if pair.beacon.y == line_to_check {
+                if is_value_in_ranges(&total_coverage, pair.beacon.x) {
+                    Some(pair.beacon.x)
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    total_values as usize - beacons_positions_to_remove.len()
+}
+
+pub fn part2(pairs: &[Pair], limits: &Limits) -> Option<i64> {
+    for line in limits.y_min..=limits.y_max {
+        if let Some(pos) = check_line(pairs, limits, line) {
+            debug!("distress beacon at {pos:?}");
+            return Some(pos.x * 4000000 + pos.y);
+        }
+    }
+    None
+}
+
+/// The first position of line `line_to_check` within `limits` that no sensor
+/// covers, `None` if they cover the whole line.
+pub fn check_line(pairs: &[Pair], limits: &Limits, line_to_check: i64) -> Option<Position> {
+    let mut total_coverage = coverage(pairs, line_to_check, limits.x_min, limits.x_max);
+
+    let total_values = total_coverage
+        .iter()
+        .fold(0, |acc, range| acc + (range.0.end() + 1 - range.0.start()));
+
+    if total_values == limits.x_max + 1 - limits.x_min {
+        None
+    } else {
+        // the first position before a range or after the previous ones
+        total_coverage.sort_by(|a, b| a.0.start().cmp(b.0.start()));
+        let mut x = limits.x_min;
+        for range in &total_coverage {
+            if *range.0.start() > x {
+                break;
+            }
+            x = x.max(range.0.end() + 1);
+        }
+        Some(Position::new(x, line_to_check))
+    }
+}
+
+// the disjoint ranges of x positions on line `line_to_check` covered by the
+// sensors, clamped to `min_x..=max_x`
+fn coverage(pairs: &[Pair], line_to_check: i64, min_x: i64, max_x: i64) -> Vec<MyRange> {
+    let mut total_coverage: Vec<MyRange> = vec![];
+
+    for pair in pairs {
+        let crosses_line = (pair.sensor.y - line_to_check).abs() <= pair.distance;
+        if !crosses_line {
+            continue;
+        }
+
+        let sensor_coverage =
+            get_x_within_distance(&pair.sensor, pair.distance, line_to_check, min_x, max_x);
+        // entirely outside of `min_x..=max_x`
+        if sensor_coverage.0.is_empty() {
+            continue;
+        }
+
+        // we try to merge it into one of the existing ranges
+        let mut merged = false;
+        for (i, range) in total_coverage.iter().enumerate() {
+            if let Some(merged_range) = range.merge(&sensor_coverage) {
+                total_coverage.remove(i);
+                total_coverage.push(merged_range);
+                merged = true;
+                break;
+            }
+        }
+        if !merged {
+            total_coverage.push(sensor_coverage);
+        }
+    }
+
+    let mut i: isize = 0;
+    while i < total_coverage.len() as isize {
+        let mut j: isize = 0;
+        while j < total_coverage.len() as isize {
+            if i == j {
+                j += 1;
+                continue;
+            }
+
+            let range = &total_coverage[i as usize];
+            let next_range = &total_coverage[j as usize];
+
+            if let Some(merged_range) = range.merge(next_range) {
+                total_coverage.remove(i as usize);
+                if j < i {
+                    total_coverage.remove(j as usize);
+                } else {
+                    total_coverage.remove((j - 1) as usize);
+                }
+                total_coverage.push(merged_range);
+                i = -1;
+                break;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    total_coverage
+}
+
+// get a list of x positions on line `y` within distance of current position
+fn get_x_within_distance(pos: &Position, distance: i64, y: i64, min_x: i64, max_x: i64) -> MyRange {
+    let max_distance = distance - (pos.y - y).abs();
+    let min = std::cmp::max(pos.x - max_distance, min_x);
+    let max = std::cmp::min(pos.x + max_distance, max_x);
+    MyRange(min..=max)
+}
+
+fn is_value_in_ranges(ranges: &[MyRange], value: i64) -> bool {
+    for range in ranges {
+        if range.0.contains(&value) {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,257 @@
+use crate::parse::{end_of_input, lines, Fields, Line, Pattern};
+use crate::{memoized, Answer, Error, NoDedup, Result, Search, Solution};
+use log::debug;
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+pub struct Valve {
+    name: String,
+    flow: u64,
+    neighbors: Vec<String>,
+}
+
+pub type Distances = HashMap<String, u64>;
+
+impl Valve {
+    fn get_neighbors(&self) -> Vec<String> {
+        self.neighbors.clone()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Minutes before the eruption when alone, in part 1.
+    pub part1_minutes: u64,
+    /// Minutes left with the elephant, in part 2.
+    pub part2_minutes: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            part1_minutes: 30,
+            part2_minutes: 26,
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = HashMap<String, Valve>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(valves: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part1(valves, options.part1_minutes)?.into())
+    }
+
+    fn part2(valves: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part2(valves, options.part2_minutes)?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<String, Valve>> {
+    let pattern = Pattern::new("Valve {name} has flow rate={flow}; {tunnels}");
+    let lines: Vec<(Line, Fields)> = lines(input)
+        .map(|line| Ok((line, pattern.matches(&line)?)))
+        .collect::<Result<_>>()?;
+    let names: HashSet<&str> = lines.iter().map(|(_, fields)| fields.str("name")).collect();
+
+    if !names.contains("AA") {
+        return Err(Error::malformed(end_of_input(input), "", "the valve `AA`"));
+    }
+
+    lines
+        .iter()
+        .map(|(line, fields)| {
+            let tunnels = fields.str("tunnels");
+            let neighbors = tunnels
+                .strip_prefix("tunnels lead to valves ")
+                .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+                .ok_or_else(|| line.malformed(tunnels, "`tunnels lead to valves <valves>`"))?
+                .split(", ")
+                .map(|neighbor| match names.contains(neighbor) {
+                    true => Ok(neighbor.to_string()),
+                    false => Err(line.invalid(neighbor, "there is no such valve")),
+                })
+                .collect::<Result<Vec<String>>>()?;
+
+            let name = fields.str("name");
+            Ok((
+                name.to_string(),
+                Valve {
+                    name: name.to_string(),
+                    flow: fields.get("flow")?,
+                    neighbors,
+                },
+            ))
+        })
+        .collect()
+}
+
+pub fn part1(valves: &HashMap<String, Valve>, minutes: u64) -> Result<u64> {
+    let distances = get_distances(valves)?;
+
+    debug!("start travel");
+    let (flow, stats) = memoized(
+        &("AA".to_string(), BTreeSet::new(), minutes),
+        |state, travel| released_from(valves, &distances, state, travel),
+    );
+    debug!("travel: {stats}");
+    Ok(flow)
+}
+
+// a valve, the valves opened before it and the minutes left
+type Travel = (String, BTreeSet<String>, u64);
+
+// the pressure released from the valve `current` on, reached with
+// `time_left` minutes left after opening the valves of `opened`
+fn released_from(
+    valves: &HashMap<String, Valve>,
+    distances: &HashMap<String, Distances>,
+    (current, opened, time_left): &Travel,
+    travel: &mut dyn FnMut(&Travel) -> u64,
+) -> u64 {
+    let released = valves[current].flow * time_left;
+    if *time_left == 0 {
+        return released;
+    }
+
+    let distances = &distances[current];
+    let mut opened = opened.clone();
+    opened.insert(current.clone());
+
+    let mut max_flow = released;
+    for valve in valves.values() {
+        if !opened.contains(&valve.name) && valve.flow > 0 {
+            let distance = &distances[&valve.name];
+
+            if time_left > distance {
+                let next = (valve.name.clone(), opened.clone(), time_left - distance - 1);
+                max_flow = max_flow.max(released + travel(&next));
+            }
+        }
+    }
+    max_flow
+}
+
+// the distance between every two valves, with the Floyd-Warshall algorithm
+// as in https://github.com/mebeim/aoc/tree/master/2022#day-16---proboscidea-volcanium
+// an error if a valve worth opening cannot be reached from `AA` or from
+// another one
+fn get_distances(valves: &HashMap<String, Valve>) -> Result<HashMap<String, Distances>> {
+    let mut distances: HashMap<String, Distances> = HashMap::new();
+
+    for (key, value) in valves {
+        for b_name in &value.get_neighbors() {
+            let a = distances.entry(key.clone()).or_default();
+            a.insert(key.clone(), 0);
+            a.insert(b_name.clone(), 1);
+
+            distances
+                .entry(b_name.clone())
+                .or_default()
+                .insert(b_name.clone(), 0);
+        }
+    }
+
+    for (a, b, c) in product(&valves.keys().cloned().collect::<Vec<String>>()) {
+        let bc = *distances.get(&b).unwrap().get(&c).unwrap_or(&100_000);
+        let ba = *distances.get(&b).unwrap().get(&a).unwrap_or(&100_000);
+        let ac = *distances.get(&a).unwrap().get(&c).unwrap_or(&100_000);
+
+        if ba + ac < bc {
+            distances.entry(b).and_modify(|x| {
+                x.insert(c, ba + ac);
+            });
+        }
+    }
+
+    let flowing: Vec<&String> = valves
+        .values()
+        .filter(|valve| valve.flow > 0)
+        .map(|valve| &valve.name)
+        .collect();
+    for from in flowing.iter().copied().chain([&"AA".to_string()]) {
+        for to in &flowing {
+            if !distances[from].contains_key(*to) {
+                return Err(Error::no_solution(format!(
+                    "the valve `{to}` cannot be reached from `{from}`"
+                )));
+            }
+        }
+    }
+    Ok(distances)
+}
+
+fn product(keys: &[String]) -> Vec<(String, String, String)> {
+    let mut res: Vec<(String, String, String)> = vec![];
+    for k1 in keys {
+        for k2 in keys {
+            for k3 in keys {
+                res.push((k1.clone(), k2.clone(), k3.clone()));
+            }
+        }
+    }
+    res
+}
+
+pub fn part2(valves: &HashMap<String, Valve>, minutes: u64) -> Result<u64> {
+    let distances = get_distances(valves)?;
+
+    // the most pressure released by opening each set of valves alone, every
+    // path being a state: the paths make a tree, nothing to deduplicate
+    let mut best: HashMap<BTreeSet<String>, u64> = HashMap::new();
+    let start = ("AA".to_string(), BTreeSet::new(), minutes, 0);
+    let stats = Search::new(start, |path: &Path| next_paths(valves, &distances, path))
+        .visited(NoDedup)
+        .for_each(|(_, opened, _, released)| {
+            let flow = best.entry(opened.clone()).or_default();
+            *flow = (*flow).max(*released);
+        });
+    debug!("paths: {stats}, {} sets of valves", best.len());
+
+    // the elephant opens other valves than me
+    let mut result = 0;
+    for (opened1, flow1) in &best {
+        for (opened2, flow2) in &best {
+            if opened1.is_disjoint(opened2) && flow1 + flow2 > result {
+                result = flow1 + flow2;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// the last valve opened, the valves opened, the minutes left and the
+// pressure they release
+type Path = (String, BTreeSet<String>, u64, u64);
+
+// the paths going on to open one more valve
+fn next_paths(
+    valves: &HashMap<String, Valve>,
+    distances: &HashMap<String, Distances>,
+    (current, opened, time_left, released): &Path,
+) -> Vec<Path> {
+    let distances = &distances[current];
+
+    valves
+        .values()
+        .filter(|valve| valve.flow > 0 && !opened.contains(&valve.name))
+        .filter(|valve| *time_left > distances[&valve.name])
+        .map(|valve| {
+            let time_left = time_left - distances[&valve.name] - 1;
+            let mut opened = opened.clone();
+            opened.insert(valve.name.clone());
+            let released = released + valve.flow * time_left;
+            (valve.name.clone(), opened, time_left, released)
+        })
+        .collect()
+}
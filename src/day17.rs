@@ -0,0 +1,349 @@
+use crate::parse::single_line;
+use crate::{
+    find_cycle, Answer, Cell, Cycle, CycleMethod, Direction, Error, Frame, Part, Recorder, Result,
+    Rgb, Solution,
+};
+use log::debug;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// A cell of the chamber: `x` from 0 to 6 between the walls, `y` from 0 on
+/// the floor.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Position(pub u64, pub u64);
+
+/// The rocks, in the order they fall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
+    HorizontalLine,
+    Plus,
+    Corner,
+    VerticalLine,
+    Square,
+}
+
+impl Rock {
+    pub const ALL: [Rock; 5] = [
+        Rock::HorizontalLine,
+        Rock::Plus,
+        Rock::Corner,
+        Rock::VerticalLine,
+        Rock::Square,
+    ];
+
+    /// The rock falling after `rocks` others.
+    pub fn nth(rocks: u64) -> Rock {
+        Rock::ALL[(rocks % 5) as usize]
+    }
+}
+
+/// A rock in the chamber, as the cells it fills.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape(Vec<Position>);
+
+impl Shape {
+    /// `rock` as it appears, two cells from the left wall with its bottom at
+    /// `y`.
+    pub fn new(rock: Rock, y: u64) -> Self {
+        Shape(match rock {
+            Rock::HorizontalLine => vec![
+                Position(2, y),
+                Position(3, y),
+                Position(4, y),
+                Position(5, y),
+            ],
+            Rock::Plus => vec![
+                Position(2, y + 1),
+                Position(3, y),
+                Position(3, y + 1),
+                Position(3, y + 2),
+                Position(4, y + 1),
+            ],
+            Rock::Corner => vec![
+                Position(2, y),
+                Position(3, y),
+                Position(4, y),
+                Position(4, y + 1),
+                Position(4, y + 2),
+            ],
+            Rock::VerticalLine => vec![
+                Position(2, y),
+                Position(2, y + 1),
+                Position(2, y + 2),
+                Position(2, y + 3),
+            ],
+            Rock::Square => vec![
+                Position(2, y),
+                Position(2, y + 1),
+                Position(3, y),
+                Position(3, y + 1),
+            ],
+        })
+    }
+
+    /// The cells of the rock.
+    pub fn positions(&self) -> &[Position] {
+        &self.0
+    }
+
+    /// The shape moved one cell towards `direction`, `None` if a wall, the
+    /// floor or an `occupied` cell is in the way.
+    pub fn moved(&self, direction: Direction, occupied: &HashSet<Position>) -> Option<Shape> {
+        let positions = self
+            .0
+            .iter()
+            .map(|&Position(x, y)| match direction {
+                Direction::Left => x.checked_sub(1).map(|x| Position(x, y)),
+                Direction::Right => (x < 6).then_some(Position(x + 1, y)),
+                Direction::Down => (y > 1).then_some(Position(x, y - 1)),
+                Direction::Up => Some(Position(x, y + 1)),
+            })
+            .collect::<Option<Vec<Position>>>()?;
+
+        match positions.iter().any(|p| occupied.contains(p)) {
+            true => None,
+            false => Some(Shape(positions)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Rocks dropped in part 1.
+    pub part1_rocks: u64,
+    /// Rocks dropped in part 2.
+    pub part2_rocks: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            part1_rocks: 2022,
+            part2_rocks: 1000000000000,
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(jets: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(run(jets, options.part1_rocks)?.into())
+    }
+
+    fn part2(jets: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(run(jets, options.part2_rocks)?.into())
+    }
+
+    // the rocks fall one by one until the tower starts repeating itself, the
+    // next ones would only show the same again
+    fn visualize(
+        jets: &Self::Input,
+        options: &Options,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        let rocks = match part {
+            Part::One => options.part1_rocks,
+            Part::Two => options.part2_rocks,
+        };
+        let mut tower = match Tower::new(jets) {
+            Some(tower) => tower,
+            None => return Some(Err(no_jets())),
+        };
+        let shown = tower_cycle(&tower, rocks).map_or(rocks, |cycle| {
+            rocks.min((cycle.start + cycle.period) as u64)
+        });
+
+        for _ in 0..shown {
+            tower.drop_rock(|tower, piece| recorder.step(|| tower.frame(piece, rocks)));
+        }
+        Some(run(jets, rocks).map(Answer::from))
+    }
+}
+
+/// The chamber after some rocks fell: what is occupied, the next jet and
+/// the next rock.
+#[derive(Debug, Clone)]
+pub struct Tower<'a> {
+    jets: &'a [Direction],
+    occupied: HashSet<Position>,
+    top_y: u64,
+    jet: usize,
+    rocks: u64,
+}
+
+impl<'a> Tower<'a> {
+    /// An empty chamber where the rocks are pushed by `jets` in turn, `None`
+    /// without jets.
+    pub fn new(jets: &'a [Direction]) -> Option<Self> {
+        (!jets.is_empty()).then(|| Tower {
+            jets,
+            occupied: (0..7).map(|x| Position(x, 0)).collect(),
+            top_y: 0,
+            jet: 0,
+            rocks: 0,
+        })
+    }
+
+    /// The height of the tower.
+    pub fn height(&self) -> u64 {
+        self.top_y
+    }
+
+    /// The number of rocks that fell.
+    pub fn rocks(&self) -> u64 {
+        self.rocks
+    }
+
+    /// Drops the next rock until it rests. `on_move` gets the tower and the
+    /// falling rock, when it appears and after every push and fall.
+    pub fn drop_rock(&mut self, mut on_move: impl FnMut(&Tower, &Shape)) {
+        let mut piece = Shape::new(Rock::nth(self.rocks), self.top_y + 4);
+        on_move(self, &piece);
+        loop {
+            let direction = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            if let Some(pushed) = piece.moved(direction, &self.occupied) {
+                piece = pushed;
+            }
+
+            match piece.moved(Direction::Down, &self.occupied) {
+                Some(fallen) => piece = fallen,
+                None => break,
+            }
+            on_move(self, &piece);
+        }
+
+        self.top_y = piece.0.iter().map(|p| p.1).fold(self.top_y, u64::max);
+        self.occupied.extend(piece.0);
+        self.rocks += 1;
+    }
+
+    /// The top of the chamber with the falling rock.
+    fn frame(&self, piece: &Shape, total: u64) -> Frame {
+        let top = piece.0.iter().map(|p| p.1).fold(self.top_y, u64::max);
+        let mut frame = Frame::new(
+            format!("rock {} of {total}, height {}", self.rocks + 1, self.top_y),
+            9,
+            VIEW_ROWS as usize,
+            AIR,
+        );
+
+        for row in 0..VIEW_ROWS.min(top + 1) {
+            let y = top - row;
+            let row = row as usize;
+            if y == 0 {
+                (0..9).for_each(|x| frame.draw((x, row), FLOOR));
+                continue;
+            }
+            frame.draw((0, row), WALL);
+            frame.draw((8, row), WALL);
+            for x in 0..7 {
+                if self.occupied.contains(&Position(x, y)) {
+                    frame.draw((x as usize + 1, row), ROCK);
+                }
+            }
+        }
+        for Position(x, y) in &piece.0 {
+            frame.draw((*x as usize + 1, (top - y) as usize), FALLING);
+        }
+        frame
+    }
+}
+
+/// Rows of the chamber in the frames.
+const VIEW_ROWS: u64 = 40;
+
+const AIR: Cell = Cell::new('.', Rgb(20, 20, 28));
+const WALL: Cell = Cell::new('|', Rgb(150, 150, 150));
+const FLOOR: Cell = Cell::new('-', Rgb(150, 150, 150));
+const ROCK: Cell = Cell::new('#', Rgb(170, 90, 50));
+const FALLING: Cell = Cell::new('@', Rgb(255, 140, 40));
+
+pub fn parse_input(input: &str) -> Result<Vec<Direction>> {
+    let line = single_line(input, "the jet pattern")?;
+    let jets = line.text.trim();
+    jets.char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err(line.malformed(&jets[i..i + c.len_utf8()], "`<` or `>`")),
+        })
+        .collect()
+}
+
+fn no_jets() -> Error {
+    Error::no_solution("there are no jets to push the rocks")
+}
+
+/// The height of the tower once `n_pieces` rocks fell.
+pub fn run(jets: &[Direction], n_pieces: u64) -> Result<u64> {
+    let mut tower = Tower::new(jets).ok_or_else(no_jets)?;
+
+    match tower_cycle(&tower, n_pieces) {
+        Some(cycle) => {
+            debug!(
+                "the tower repeats every {} rocks after {} rocks",
+                cycle.period, cycle.start
+            );
+            Ok(cycle.metric_at(n_pieces)? as u64)
+        }
+        None => {
+            for _ in 0..n_pieces {
+                tower.drop_rock(|_, _| {});
+            }
+            Ok(tower.top_y)
+        }
+    }
+}
+
+// the height of the tower repeats once the rocks fall the same way, `None`
+// if it does not within `n_pieces` rocks
+fn tower_cycle(tower: &Tower, n_pieces: u64) -> Option<Cycle> {
+    // what comes next only depends on the next jet, the next rock and the
+    // top of the tower, 30 rows being enough for the rocks not to go lower
+    find_cycle(
+        tower.clone(),
+        |tower| tower.drop_rock(|_, _| {}),
+        |tower| {
+            (
+                tower.jet,
+                tower.rocks % 5,
+                get_latest_rows(&tower.occupied, 30, tower.top_y),
+            )
+        },
+        |tower| tower.top_y as i64,
+        CycleMethod::Hashing,
+        n_pieces as usize,
+    )
+}
+
+fn get_latest_rows(
+    occupied_positions: &HashSet<Position>,
+    n_rows: u64,
+    top_row: u64,
+) -> Vec<Position> {
+    let mut latest_rows: Vec<Position> = vec![];
+
+    let min_y = top_row.saturating_sub(n_rows);
+
+    for y in min_y..=top_row {
+        for x in 0..=6 {
+            if occupied_positions.contains(&Position(x, y)) {
+                latest_rows.push(Position(x, y - min_y));
+            }
+        }
+    }
+
+    latest_rows.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    latest_rows
+}
@@ -0,0 +1,78 @@
+use crate::parse::{end_of_input, lines};
+use crate::{Answer, BoundingBox, Error, NoOptions, Point3, Result, Search, Solution};
+use log::debug;
+use std::collections::HashSet;
+
+pub type Cube = Point3<i32>;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Cube>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(cubes: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(cubes).into())
+    }
+
+    fn part2(cubes: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part2(cubes).into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Cube>> {
+    let cubes = lines(input)
+        .map(|line| {
+            let [x, y, z] = line.split_n(",", "a cube like `2,2,2`")?;
+            Ok(Cube::new(line.parse(x)?, line.parse(y)?, line.parse(z)?))
+        })
+        .collect::<Result<Vec<Cube>>>()?;
+
+    // part2 needs the bounding box of the droplet
+    if cubes.is_empty() {
+        return Err(Error::malformed(
+            end_of_input(input),
+            "",
+            "at least one cube",
+        ));
+    }
+    Ok(cubes)
+}
+
+pub fn part1(cubes: &[Cube]) -> usize {
+    let mut total = cubes.len() * 6;
+    for cube in cubes {
+        for neighbor in cube.neighbours6() {
+            if cubes.contains(&neighbor) {
+                total -= 1;
+            }
+        }
+    }
+    total
+}
+
+pub fn part2(cubes: &[Cube]) -> usize {
+    // the water flows around the droplet, one cube away from it
+    let bounds = BoundingBox::from_points(cubes)
+        .expect("parsing checks there is a cube")
+        .expanded(1);
+
+    let lava: HashSet<Cube> = cubes.iter().copied().collect();
+    let (water, stats) = Search::new(bounds.min, |cube: &Cube| {
+        cube.neighbours6()
+            .filter(|c| bounds.contains(c) && !lava.contains(c))
+            .collect::<Vec<_>>()
+    })
+    .reachable();
+    debug!("flood fill: {stats}");
+    let water: HashSet<Cube> = water.into_iter().collect();
+
+    cubes
+        .iter()
+        .map(|cube| cube.neighbours6().filter(|c| water.contains(c)).count())
+        .sum()
+}
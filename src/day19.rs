@@ -0,0 +1,497 @@
+use crate::parse::{lines, Pattern};
+use crate::{Answer, Result, Search, SeenKeys, Solution};
+use log::debug;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::thread;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Rock {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    index: u32,
+    costs: HashMap<Rock, HashMap<Rock, u32>>,
+}
+
+impl Blueprint {
+    fn max_cost_per_rock(&self, rock: Rock) -> Option<u32> {
+        self.costs
+            .values()
+            .flat_map(|costs| {
+                costs
+                    .iter()
+                    .filter_map(|(r, cost)| if *r == rock { Some(*cost) } else { None })
+            })
+            .max()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct State {
+    time: u32,
+
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct StateWithPreviousUnbuiltRobots {
+    state: State,
+    not_built: Vec<Rock>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Minutes to crack geodes in part 1.
+    pub part1_minutes: u32,
+    /// Minutes to crack geodes in part 2.
+    pub part2_minutes: u32,
+    /// Blueprints left after the elephants ate the others, in part 2.
+    pub part2_blueprints: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            part1_minutes: 24,
+            part2_minutes: 32,
+            part2_blueprints: 3,
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(blueprints: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part1(blueprints, options.part1_minutes).into())
+    }
+
+    fn part2(blueprints: &Self::Input, options: &Options) -> Result<Answer> {
+        Ok(part2(blueprints, options.part2_minutes, options.part2_blueprints).into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Blueprint>> {
+    let pattern = Pattern::new("Blueprint {id}: Each ore robot costs {ore} ore. Each clay robot costs {clay} ore. Each obsidian robot costs {obsidian_ore} ore and {obsidian_clay} clay. Each geode robot costs {geode_ore} ore and {geode_obsidian} obsidian.");
+
+    lines(input)
+        .map(|line| {
+            let fields = pattern.matches(&line)?;
+            let cost = |name: &str| fields.get::<u32>(name);
+
+            Ok(Blueprint {
+                index: cost("id")?,
+                costs: HashMap::from([
+                    (Rock::Ore, HashMap::from([(Rock::Ore, cost("ore")?)])),
+                    (Rock::Clay, HashMap::from([(Rock::Ore, cost("clay")?)])),
+                    (
+                        Rock::Obsidian,
+                        HashMap::from([
+                            (Rock::Ore, cost("obsidian_ore")?),
+                            (Rock::Clay, cost("obsidian_clay")?),
+                        ]),
+                    ),
+                    (
+                        Rock::Geode,
+                        HashMap::from([
+                            (Rock::Ore, cost("geode_ore")?),
+                            (Rock::Obsidian, cost("geode_obsidian")?),
+                        ]),
+                    ),
+                ]),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(blueprints: &[Blueprint], minutes: u32) -> u32 {
+    max_geodes(blueprints.iter(), minutes)
+        .map(|(bp, geodes)| bp.index * geodes)
+        .sum()
+}
+
+/*
+For Part 2, used optimisations from:
+https://aoc.just2good.co.uk/2022/19
+https://github.com/mebeim/aoc/blob/master/2022/README.md#day-19---not-enough-minerals
+*/
+pub fn part2(blueprints: &[Blueprint], minutes: u32, kept: u32) -> u32 {
+    max_geodes(blueprints.iter().filter(|bp| bp.index <= kept), minutes)
+        .map(|(_, geodes)| geodes)
+        .product()
+}
+
+// runs each blueprint in its own thread
+fn max_geodes<'a>(
+    blueprints: impl Iterator<Item = &'a Blueprint>,
+    time: u32,
+) -> impl Iterator<Item = (&'a Blueprint, u32)> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = blueprints
+            .map(|bp| scope.spawn(move || (bp, run_blueprint(bp, time))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    })
+    .into_iter()
+}
+
+pub fn run_blueprint(bp: &Blueprint, time: u32) -> u32 {
+    if time == 0 {
+        return 0;
+    }
+
+    let start = StateWithPreviousUnbuiltRobots {
+        state: State {
+            time,
+
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        },
+        not_built: vec![],
+    };
+
+    let cost_ore_in_ore = bp.costs[&Rock::Ore][&Rock::Ore];
+    let cost_clay_in_ore = bp.costs[&Rock::Clay][&Rock::Ore];
+    let cost_obsidian_in_ore = bp.costs[&Rock::Obsidian][&Rock::Ore];
+    let cost_obsidian_in_clay = bp.costs[&Rock::Obsidian][&Rock::Clay];
+    let cost_geode_in_ore = bp.costs[&Rock::Geode][&Rock::Ore];
+    let cost_geode_in_obsidian = bp.costs[&Rock::Geode][&Rock::Obsidian];
+
+    let max_ore_needed = bp.max_cost_per_rock(Rock::Ore).unwrap();
+    let max_clay_needed = bp.max_cost_per_rock(Rock::Clay).unwrap();
+    let max_obsidian_needed = bp.max_cost_per_rock(Rock::Obsidian).unwrap();
+
+    // the robots and the stocks that cannot be spent anymore are trimmed,
+    // so that more states are the same, then a minute passes
+    let next_minute = |state: &State| {
+        let mut state = *state;
+        state.ore_robots = std::cmp::min(state.ore_robots, max_ore_needed);
+        state.clay_robots = std::cmp::min(state.clay_robots, max_clay_needed);
+        state.obsidian_robots = std::cmp::min(state.obsidian_robots, max_obsidian_needed);
+        state.ore = std::cmp::min(
+            state.ore,
+            state.time * max_ore_needed - (state.ore_robots * (state.time - 1)),
+        );
+        state.clay = std::cmp::min(
+            state.clay,
+            state.time * max_clay_needed - (state.clay_robots * (state.time - 1)),
+        );
+        state.obsidian = std::cmp::min(
+            state.obsidian,
+            state.time * max_obsidian_needed - (state.obsidian_robots * (state.time - 1)),
+        );
+        state.time -= 1;
+        state
+    };
+
+    let successors = |current: &StateWithPreviousUnbuiltRobots| {
+        let mut states = vec![];
+        let State {
+            time,
+            ore,
+            clay,
+            obsidian,
+            geode,
+            ore_robots,
+            clay_robots,
+            obsidian_robots,
+            geode_robots,
+        } = next_minute(&current.state);
+        let not_built = &current.not_built;
+
+        if time == 0 {
+            return states;
+        }
+
+        let mut can_build = vec![];
+
+        if ore >= cost_ore_in_ore && ore_robots < max_ore_needed && !not_built.contains(&Rock::Ore)
+        {
+            can_build.push(Rock::Ore);
+            states.push(StateWithPreviousUnbuiltRobots {
+                state: State {
+                    time,
+                    ore: ore + ore_robots - cost_ore_in_ore,
+                    clay: clay + clay_robots,
+                    obsidian: obsidian + obsidian_robots,
+                    geode: geode + geode_robots,
+                    ore_robots: ore_robots + 1,
+                    clay_robots,
+                    obsidian_robots,
+                    geode_robots,
+                },
+                not_built: vec![],
+            });
+        }
+
+        if ore >= cost_clay_in_ore
+            && clay_robots < max_clay_needed
+            && !not_built.contains(&Rock::Clay)
+        {
+            can_build.push(Rock::Clay);
+            states.push(StateWithPreviousUnbuiltRobots {
+                state: State {
+                    time,
+                    ore: ore + ore_robots - cost_clay_in_ore,
+                    clay: clay + clay_robots,
+                    obsidian: obsidian + obsidian_robots,
+                    geode: geode + geode_robots,
+                    ore_robots,
+                    clay_robots: clay_robots + 1,
+                    obsidian_robots,
+                    geode_robots,
+                },
+                not_built: vec![],
+            });
+        }
+
+        if ore >= cost_obsidian_in_ore
+            && clay >= cost_obsidian_in_clay
+            && obsidian_robots < max_obsidian_needed
+            && !not_built.contains(&Rock::Obsidian)
+        {
+            can_build.push(Rock::Obsidian);
+            states.push(StateWithPreviousUnbuiltRobots {
+                state: State {
+                    time,
+                    ore: ore + ore_robots - cost_obsidian_in_ore,
+                    clay: clay + clay_robots - cost_obsidian_in_clay,
+                    obsidian: obsidian + obsidian_robots,
+                    geode: geode + geode_robots,
+                    ore_robots,
+                    clay_robots,
+                    obsidian_robots: obsidian_robots + 1,
+                    geode_robots,
+                },
+                not_built: vec![],
+            });
+        }
+
+        if ore >= cost_geode_in_ore
+            && obsidian >= cost_geode_in_obsidian
+            && !not_built.contains(&Rock::Geode)
+        {
+            can_build.push(Rock::Geode);
+            states.push(StateWithPreviousUnbuiltRobots {
+                state: State {
+                    time,
+                    ore: ore + ore_robots - cost_geode_in_ore,
+                    clay: clay + clay_robots,
+                    obsidian: obsidian + obsidian_robots - cost_geode_in_obsidian,
+                    geode: geode + geode_robots,
+                    ore_robots,
+                    clay_robots,
+                    obsidian_robots,
+                    geode_robots: geode_robots + 1,
+                },
+                not_built: vec![],
+            });
+        }
+
+        if ore < max_ore_needed
+            || (clay_robots > 0 && clay < max_clay_needed)
+            || (obsidian_robots > 0 && obsidian < max_obsidian_needed)
+        {
+            states.push(StateWithPreviousUnbuiltRobots {
+                state: State {
+                    time,
+                    ore: ore + ore_robots,
+                    clay: clay + clay_robots,
+                    obsidian: obsidian + obsidian_robots,
+                    geode: geode + geode_robots,
+                    ore_robots,
+                    clay_robots,
+                    obsidian_robots,
+                    geode_robots,
+                },
+                not_built: can_build,
+            });
+        }
+        states
+    };
+
+    let mut max_geodes = 0;
+    let stats = Search::new(start, successors)
+        .visited(SeenKeys::new(|current: &StateWithPreviousUnbuiltRobots| {
+            next_minute(&current.state)
+        }))
+        .for_each(|current| {
+            let state = next_minute(&current.state);
+            if state.time == 0 {
+                max_geodes = std::cmp::max(max_geodes, state.geode + state.geode_robots);
+            }
+        });
+    debug!("blueprint {}: {stats}", bp.index);
+
+    max_geodes
+}
+
+/// Alternative to [`run_blueprint`]: tries every robot at every minute
+/// depth first, skipping the states already tried. Slower, but with fewer
+/// shortcuts.
+pub fn run_blueprint_recursive(bp: &Blueprint, time: u32) -> u32 {
+    let robots: HashMap<Rock, u32> = HashMap::from([
+        (Rock::Ore, 1),
+        (Rock::Clay, 0),
+        (Rock::Obsidian, 0),
+        (Rock::Geode, 0),
+    ]);
+    let rocks: HashMap<Rock, u32> = HashMap::from([
+        (Rock::Ore, 0),
+        (Rock::Clay, 0),
+        (Rock::Obsidian, 0),
+        (Rock::Geode, 0),
+    ]);
+
+    let mut already_tested: HashSet<State> = HashSet::new();
+    let result = run_blueprint_inner(bp, &rocks, &robots, time, &mut already_tested);
+
+    debug!(
+        "blueprint {}: {result} geodes, {} states",
+        bp.index,
+        already_tested.len()
+    );
+    result
+}
+
+fn run_blueprint_inner(
+    bp: &Blueprint,
+    rocks: &HashMap<Rock, u32>,
+    robots: &HashMap<Rock, u32>,
+    time_left: u32,
+    already_tested: &mut HashSet<State>,
+) -> u32 {
+    if time_left == 0 {
+        return rocks[&Rock::Geode];
+    }
+
+    let mut rocks = rocks.clone();
+
+    for (rock, stock) in &mut rocks {
+        if let Some(max_cost) = bp.max_cost_per_rock(*rock) {
+            // https://aoc.just2good.co.uk/2022/19
+            let max_needed = (time_left * max_cost).saturating_sub(robots[rock] * (time_left - 1));
+            if *stock > max_needed {
+                *stock = max_needed;
+            }
+        }
+    }
+
+    let state = State {
+        time: time_left,
+        ore: rocks[&Rock::Ore],
+        clay: rocks[&Rock::Clay],
+        obsidian: rocks[&Rock::Obsidian],
+        geode: rocks[&Rock::Geode],
+        ore_robots: robots[&Rock::Ore],
+        clay_robots: robots[&Rock::Clay],
+        obsidian_robots: robots[&Rock::Obsidian],
+        geode_robots: robots[&Rock::Geode],
+    };
+    if !already_tested.insert(state) {
+        return rocks[&Rock::Geode];
+    }
+
+    let mut bests = vec![rocks[&Rock::Geode]];
+
+    let mut _rocks = rocks.clone();
+    for (robot, count) in robots {
+        _rocks.entry(*robot).and_modify(|r| *r += count);
+    }
+
+    bests.push(run_blueprint_inner(
+        bp,
+        &_rocks,
+        robots,
+        time_left - 1,
+        already_tested,
+    ));
+
+    'main_loop: for (robot_type, costs) in &bp.costs {
+        if let Some(max_cost) = bp.max_cost_per_rock(*robot_type) {
+            if robots[robot_type] >= max_cost {
+                continue;
+            }
+        }
+
+        let mut _robots = robots.clone();
+        let mut _rocks = rocks.clone();
+
+        for (_rock, _cost) in costs {
+            if _rocks[_rock] < *_cost {
+                continue 'main_loop;
+            }
+            _rocks.entry(*_rock).and_modify(|r| *r -= _cost);
+        }
+
+        for (robot, count) in &_robots {
+            _rocks.entry(*robot).and_modify(|r| *r += count);
+        }
+        _robots.entry(*robot_type).and_modify(|r| *r += 1);
+        bests.push(run_blueprint_inner(
+            bp,
+            &_rocks,
+            &_robots,
+            time_left - 1,
+            already_tested,
+        ));
+    }
+
+    bests.into_iter().max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/day19/example1.txt");
+
+    #[test]
+    fn test_run_blueprint_recursive() {
+        let blueprints = parse_input(EXAMPLE1).unwrap();
+        // the 24 minutes of the puzzle take a while in debug builds
+        for bp in &blueprints {
+            for minutes in [0, 1, 10, 19] {
+                assert_eq!(
+                    run_blueprint_recursive(bp, minutes),
+                    run_blueprint(bp, minutes),
+                    "blueprint {} in {minutes} minutes",
+                    bp.index
+                );
+            }
+        }
+        assert_eq!(run_blueprint_recursive(&blueprints[0], 19), 1);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::parse::{end_of_input, parse_lines};
+use crate::{Answer, Error, Result, Solution};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncryptedNumber {
+    index: usize,
+    value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Every number is multiplied by the key in part 2.
+    pub decryption_key: i64,
+    /// Times the file is mixed in part 2.
+    pub rounds: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            decryption_key: 811589153,
+            rounds: 10,
+        }
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<EncryptedNumber>;
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Options) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input, options: &Options) -> Result<Answer> {
+        let input = decrypt(input, options.decryption_key)?;
+        Ok(part2(&input, options.rounds)?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<EncryptedNumber>> {
+    let numbers: Vec<EncryptedNumber> = parse_lines(input)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| EncryptedNumber { index, value })
+        .collect();
+
+    // the grove coordinates are found from the 0
+    if !numbers.iter().any(|n| n.value == 0) {
+        return Err(Error::malformed(end_of_input(input), "", "a 0 in the file"));
+    }
+    Ok(numbers)
+}
+
+/// The numbers multiplied by the decryption key.
+pub fn decrypt(input: &[EncryptedNumber], key: i64) -> Result<Vec<EncryptedNumber>> {
+    input
+        .iter()
+        .map(|n| {
+            let value = n.value.checked_mul(key).ok_or_else(|| {
+                Error::overflow(format!("{} times the decryption key {key}", n.value))
+            })?;
+            Ok(EncryptedNumber { value, ..*n })
+        })
+        .collect()
+}
+
+/// Where the number at `index` lands once moved by `value`, wrapping around
+/// the other numbers without overflowing for large values. A number alone
+/// stays where it is.
+fn new_index(index: usize, value: i64, len: usize) -> usize {
+    if len < 2 {
+        return index;
+    }
+    let others = (len - 1) as i64;
+    (index as i64 + value.rem_euclid(others)).rem_euclid(others) as usize
+}
+
+/// The sum of the numbers 1000, 2000 and 3000 after the 0.
+fn grove_coordinates(input: &[EncryptedNumber]) -> Result<i64> {
+    let index_0 = input.iter().position(|n| n.value == 0).unwrap();
+
+    [1000, 2000, 3000]
+        .iter()
+        .map(|i| input[(index_0 + i) % input.len()].value)
+        .try_fold(0i64, |sum, value| sum.checked_add(value))
+        .ok_or_else(|| Error::overflow("the sum of the grove coordinates"))
+}
+
+pub fn part1(input: &[EncryptedNumber]) -> Result<i64> {
+    let mut input = input.to_vec();
+
+    let mut i = 0;
+    while i < input.len() {
+        let current_index = input.iter().position(|n| n.index == i).unwrap();
+        let element = *input.get(current_index).unwrap();
+        let to_move = element.value;
+
+        let new_index = new_index(current_index, to_move, input.len());
+
+        input.remove(current_index);
+        input.insert(new_index, element);
+
+        i += 1;
+    }
+
+    grove_coordinates(&input)
+}
+
+pub fn part2(input: &[EncryptedNumber], rounds: usize) -> Result<i64> {
+    let mut input = input.to_vec();
+
+    for _ in 0..rounds {
+        let mut i = 0;
+        while i < input.len() {
+            let current_index = input.iter().position(|n| n.index == i).unwrap();
+            let element = *input.get(current_index).unwrap();
+            let to_move = element.value;
+
+            let new_index = new_index(current_index, to_move, input.len());
+
+            input.remove(current_index);
+            input.insert(new_index, element);
+
+            i += 1;
+        }
+    }
+
+    grove_coordinates(&input)
+}
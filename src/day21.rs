@@ -0,0 +1,237 @@
+use crate::parse::{end_of_input, lines, Line};
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum MonkeyJob {
+    Number(i64),
+    Operation(Operation),
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Operator {
+    Add,
+    Div,
+    Mul,
+    Sub,
+}
+
+impl Operator {
+    /// `lhs <op> rhs`, numbers getting negative when subtracted but never
+    /// wrapping around.
+    fn result(&self, lhs: i64, rhs: i64) -> Result<i64> {
+        let (result, symbol) = match self {
+            Operator::Add => (lhs.checked_add(rhs), '+'),
+            Operator::Sub => (lhs.checked_sub(rhs), '-'),
+            Operator::Mul => (lhs.checked_mul(rhs), '*'),
+            Operator::Div if rhs == 0 => {
+                return Err(Error::no_solution(format!("a monkey divides {lhs} by 0")));
+            }
+            Operator::Div => (lhs.checked_div(rhs), '/'),
+        };
+        result.ok_or_else(|| Error::overflow(format!("`{lhs} {symbol} {rhs}`")))
+    }
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    operator: Operator,
+    lhs: String,
+    rhs: String,
+}
+
+/// The jobs of part 2 as a tree, `humn` being the unknown number.
+#[derive(Debug, Clone)]
+enum Expression {
+    Humn,
+    Number(i64),
+    Operation(Operator, Box<Expression>, Box<Expression>),
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, MonkeyJob>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let jobs = parse_input(input)?;
+        for monkey in ["root", "humn"] {
+            if !jobs.contains_key(monkey) {
+                let expected = format!("the monkey `{monkey}`");
+                return Err(Error::malformed(end_of_input(input), "", expected));
+            }
+        }
+        Ok(jobs)
+    }
+
+    fn part1(jobs: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(jobs)?.into())
+    }
+
+    fn part2(jobs: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        part2(jobs)?
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("cannot find the number to yell"))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<String, MonkeyJob>> {
+    let lines: Vec<(Line, [&str; 2])> = lines(input)
+        .map(|line| Ok((line, line.split_n(": ", "`<name>: <job>`")?)))
+        .collect::<Result<_>>()?;
+    let names: HashSet<&str> = lines.iter().map(|(_, [name, _])| *name).collect();
+
+    lines
+        .iter()
+        .map(|(line, [name, operation])| {
+            Ok((
+                name.to_string(),
+                match operation {
+                    s if s.parse::<i64>().is_ok() => MonkeyJob::Number(line.parse(s)?),
+                    _ => {
+                        let [lhs, op, rhs] = parse_operation(line, operation, &names)?;
+                        MonkeyJob::Operation(Operation {
+                            operator: match op {
+                                "+" => Operator::Add,
+                                "-" => Operator::Sub,
+                                "/" => Operator::Div,
+                                "*" => Operator::Mul,
+                                _ => return Err(line.malformed(op, "`+`, `-`, `*` or `/`")),
+                            },
+                            lhs: lhs.to_string(),
+                            rhs: rhs.to_string(),
+                        })
+                    }
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Splits `<lhs> <op> <rhs>`, checking that both monkeys exist.
+fn parse_operation<'a>(
+    line: &Line,
+    operation: &'a str,
+    names: &HashSet<&str>,
+) -> Result<[&'a str; 3]> {
+    let [lhs, op, rhs]: [&str; 3] = operation
+        .split(' ')
+        .collect::<Vec<&str>>()
+        .try_into()
+        .map_err(|_| line.malformed(operation, "a number or `<monkey> <op> <monkey>`"))?;
+    for monkey in [lhs, rhs] {
+        if !names.contains(monkey) {
+            return Err(line.invalid(monkey, "there is no such monkey"));
+        }
+    }
+    Ok([lhs, op, rhs])
+}
+
+pub fn part1(jobs: &HashMap<String, MonkeyJob>) -> Result<i64> {
+    monkey_number(&"root".to_string(), jobs)
+}
+
+fn monkey_number(monkey: &String, jobs: &HashMap<String, MonkeyJob>) -> Result<i64> {
+    match jobs.get(monkey).unwrap() {
+        MonkeyJob::Number(x) => Ok(*x),
+        MonkeyJob::Operation(op) => op
+            .operator
+            .result(monkey_number(&op.lhs, jobs)?, monkey_number(&op.rhs, jobs)?),
+    }
+}
+
+pub fn part2(jobs: &HashMap<String, MonkeyJob>) -> Result<Option<i64>> {
+    let root = &jobs[&"root".to_string()];
+
+    // root's operator is ignored: both sides must be equal
+    match root {
+        MonkeyJob::Operation(root) => {
+            let lhs = to_expression(jobs, &root.lhs)?;
+            let rhs = to_expression(jobs, &root.rhs)?;
+            Ok(Some(solve_equal(&lhs, &rhs)?))
+        }
+        MonkeyJob::Number(_) => Ok(None),
+    }
+}
+
+/// The number `humn` must yell for both sides to be equal, undoing the
+/// operations of the side it is in one by one.
+fn solve_equal(lhs: &Expression, rhs: &Expression) -> Result<i64> {
+    match (lhs, rhs) {
+        (Expression::Humn, Expression::Number(n)) | (Expression::Number(n), Expression::Humn) => {
+            Ok(*n)
+        }
+        (Expression::Number(_), Expression::Operation(..)) => solve_equal(rhs, lhs),
+        (Expression::Operation(operator, op_lhs, op_rhs), Expression::Number(n)) => {
+            let n = *n;
+            match (compute_value(op_lhs)?, compute_value(op_rhs)?) {
+                (Some(op_left), None) => {
+                    let inverse = match operator {
+                        Operator::Add => Operator::Sub.result(n, op_left),
+                        Operator::Sub => Operator::Sub.result(op_left, n),
+                        Operator::Mul => Operator::Div.result(n, op_left),
+                        Operator::Div => Operator::Div.result(op_left, n),
+                    };
+                    solve_equal(op_rhs, &Expression::Number(inverse?))
+                }
+                (None, Some(op_right)) => {
+                    let inverse = match operator {
+                        Operator::Add => Operator::Sub.result(n, op_right),
+                        Operator::Sub => Operator::Add.result(n, op_right),
+                        Operator::Mul => Operator::Div.result(n, op_right),
+                        Operator::Div => Operator::Mul.result(n, op_right),
+                    };
+                    solve_equal(op_lhs, &Expression::Number(inverse?))
+                }
+                _ => Err(both_sides()),
+            }
+        }
+        (Expression::Number(_), Expression::Number(_)) => Err(Error::no_solution(
+            "`humn` is not one of the monkeys root listens to",
+        )),
+        _ => Err(both_sides()),
+    }
+}
+
+fn both_sides() -> Error {
+    Error::no_solution("`humn` is on both sides of an operation")
+}
+
+fn to_expression(jobs: &HashMap<String, MonkeyJob>, monkey: &String) -> Result<Expression> {
+    if monkey == "humn" {
+        return Ok(Expression::Humn);
+    }
+
+    match jobs.get(monkey).unwrap() {
+        MonkeyJob::Number(x) => Ok(Expression::Number(*x)),
+        MonkeyJob::Operation(op) => {
+            let lhs = to_expression(jobs, &op.lhs)?;
+            let rhs = to_expression(jobs, &op.rhs)?;
+
+            if let (Expression::Number(l), Expression::Number(r)) = (&lhs, &rhs) {
+                Ok(Expression::Number(op.operator.result(*l, *r)?))
+            } else {
+                Ok(Expression::Operation(
+                    op.operator,
+                    Box::new(lhs),
+                    Box::new(rhs),
+                ))
+            }
+        }
+    }
+}
+
+/// The value of `expression`, `None` when it depends on `humn`.
+fn compute_value(expression: &Expression) -> Result<Option<i64>> {
+    match expression {
+        Expression::Humn => Ok(None),
+        Expression::Number(x) => Ok(Some(*x)),
+        Expression::Operation(operator, lhs, rhs) => {
+            match (compute_value(lhs)?, compute_value(rhs)?) {
+                (Some(lhs), Some(rhs)) => Ok(Some(operator.result(lhs, rhs)?)),
+                _ => Ok(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,604 @@
+use crate::parse::{blocks, end_of_input, Line};
+use crate::{Answer, Cell, Error, Frame, Grid, Part, Recorder, Result, Rgb, Solution};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Path {
+    Steps(usize),
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+use Direction::*;
+impl Direction {
+    fn change_direction(&self, shift: &Path) -> Self {
+        let mut current = *self as i8;
+        current += match shift {
+            Path::Left => -1,
+            Path::Right => 1,
+            _ => panic!("wrong direction"),
+        };
+        Direction::i8_to_enum(current.rem_euclid(4))
+    }
+
+    fn arrow(self) -> char {
+        match self {
+            Right => '>',
+            Down => 'v',
+            Left => '<',
+            Up => '^',
+        }
+    }
+
+    fn i8_to_enum(n: i8) -> Self {
+        match n {
+            0 => Self::Right,
+            1 => Self::Down,
+            2 => Self::Left,
+            3 => Self::Up,
+            _ => panic!("wrong direction"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Nil,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Position(pub usize, pub usize);
+
+type Face = Vec<Vec<Tile>>;
+
+#[derive(Debug)]
+struct CubeFace {
+    face: Face,
+    start_position: Position,
+}
+
+#[derive(Debug)]
+pub struct Cube {
+    face_size: usize,
+    current_id: usize,
+    faces: HashMap<usize, CubeFace>,
+}
+
+impl Cube {
+    fn new(face_size: usize) -> Self {
+        Self {
+            face_size,
+            current_id: 0,
+            faces: HashMap::new(),
+        }
+    }
+
+    /// Cuts the map into its faces, numbered row by row. `None` if the map
+    /// is not made of `face_size` wide faces.
+    pub fn from_map(map: &Grid<Tile>, face_size: usize) -> Option<Self> {
+        if face_size == 0 || !map.height().is_multiple_of(face_size) {
+            return None;
+        }
+
+        let mut cube = Self::new(face_size);
+        for top in (0..map.height()).step_by(face_size) {
+            let row = map.row(top);
+            let start = row.iter().position(|tile| *tile != Tile::Nil)?;
+            let end = row.iter().rposition(|tile| *tile != Tile::Nil)? + 1;
+
+            for left in (start..end).step_by(face_size) {
+                let face = (0..face_size)
+                    .map(|y| {
+                        (0..face_size)
+                            .map(|x| map.get((left + x, top + y)).copied().unwrap_or(Tile::Nil))
+                            .collect()
+                    })
+                    .collect();
+                cube.add_face(face, Position(left + 1, top + 1));
+            }
+        }
+        Some(cube)
+    }
+
+    fn add_face(&mut self, face: Face, start_position: Position) {
+        self.faces.insert(
+            self.current_id,
+            CubeFace {
+                face,
+                start_position,
+            },
+        );
+        self.current_id += 1;
+    }
+
+    fn get_face(&self, n: usize) -> &Face {
+        &self.faces.get(&n).unwrap().face
+    }
+
+    /// Where `position` of face `n` is on the map, both 0-based.
+    fn map_position(&self, n: usize, position: Position) -> Position {
+        let start = self.faces[&n].start_position;
+        Position(start.0 - 1 + position.0, start.1 - 1 + position.1)
+    }
+}
+
+/// How the faces of the map fold into a cube.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Layout {
+    /// The net of the real inputs.
+    Input,
+    /// The net of the example.
+    Example,
+}
+
+impl Layout {
+    pub fn switch_face(self) -> TSwitchFace {
+        match self {
+            Layout::Input => switch_face_main,
+            Layout::Example => switch_face_example,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    /// Side of a face of the cube, in part 2.
+    pub face_size: usize,
+    /// How the faces fold, in part 2.
+    pub layout: Layout,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            face_size: 50,
+            layout: Layout::Input,
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Grid<Tile>, Vec<Path>);
+    type Options = Options;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((map, path): &Self::Input, _: &Options) -> Result<Answer> {
+        Ok(part1(map, path).into())
+    }
+
+    fn part2((map, path): &Self::Input, options: &Options) -> Result<Answer> {
+        let size = options.face_size;
+        part2(map, path, size, options.layout.switch_face())
+            .map(Answer::from)
+            .ok_or_else(|| {
+                Error::no_solution(format!("the map is not made of {size}x{size} faces"))
+            })
+    }
+
+    fn visualize(
+        (map, path): &Self::Input,
+        options: &Options,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        // the last direction the path took on each tile, drawn like the
+        // puzzle text
+        let mut trail: Grid<Option<Direction>> = map.map(|_| None);
+        let mut steps = 0;
+        let mut on_step = |position: Position, direction: Direction| {
+            trail[(position.0, position.1)] = Some(direction);
+            steps += 1;
+            recorder.step(|| map_frame(map, &trail, position, steps));
+        };
+
+        let answer = match part {
+            Part::One => Ok(follow_path(map, path, &mut on_step)),
+            Part::Two => {
+                let size = options.face_size;
+                let switch_face = options.layout.switch_face();
+                follow_cube_path(map, path, size, switch_face, &mut on_step).ok_or_else(|| {
+                    Error::no_solution(format!("the map is not made of {size}x{size} faces"))
+                })
+            }
+        };
+        Some(answer.map(Answer::from))
+    }
+}
+
+const VOID: Cell = Cell::new(' ', Rgb(0, 0, 0));
+const OPEN: Cell = Cell::new('.', Rgb(60, 90, 60));
+const WALL: Cell = Cell::new('#', Rgb(140, 140, 140));
+const TRAIL: Rgb = Rgb(240, 200, 80);
+const CURRENT: Rgb = Rgb(255, 80, 80);
+
+fn map_frame(
+    map: &Grid<Tile>,
+    trail: &Grid<Option<Direction>>,
+    current: Position,
+    steps: usize,
+) -> Frame {
+    let mut frame = Frame::new(
+        format!(
+            "step {steps} at row {}, column {}",
+            current.1 + 1,
+            current.0 + 1
+        ),
+        map.width(),
+        map.height(),
+        VOID,
+    );
+    for ((x, y), tile) in map.iter() {
+        let cell = match (tile, trail[(x, y)]) {
+            (_, Some(direction)) if (x, y) == (current.0, current.1) => {
+                Cell::new(direction.arrow(), CURRENT)
+            }
+            (_, Some(direction)) => Cell::new(direction.arrow(), TRAIL),
+            (Tile::Empty, None) => OPEN,
+            (Tile::Wall, None) => WALL,
+            (Tile::Nil, None) => VOID,
+        };
+        frame.draw((x, y), cell);
+    }
+    frame
+}
+
+pub fn parse_input(input: &str) -> Result<(Grid<Tile>, Vec<Path>)> {
+    let blocks = blocks(input);
+    let [_map, _path] = blocks.as_slice() else {
+        return Err(Error::malformed(
+            end_of_input(input),
+            "",
+            "the map and the path separated by a blank line",
+        ));
+    };
+
+    // the rows stop after their last tile, the map is padded to its widest row
+    let map = Grid::from_lines(
+        _map,
+        Some(Tile::Nil),
+        |_, c| match c {
+            ' ' => Some(Tile::Nil),
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        },
+        "` `, `.` or `#`",
+    )?;
+
+    if map.row(0).iter().all(|tile| *tile == Tile::Nil) {
+        return Err(_map[0].malformed(_map[0].text, "an open tile to start from"));
+    }
+
+    let [path_line] = _path.as_slice() else {
+        return Err(_path[1].malformed(_path[1].text, "the path on a single line"));
+    };
+    Ok((map, parse_path(path_line)?))
+}
+
+fn parse_path(line: &Line) -> Result<Vec<Path>> {
+    let path = line.text.trim();
+    let mut steps = vec![];
+    let mut number_start = None;
+
+    for (i, c) in path.char_indices() {
+        match c {
+            '0'..='9' => {
+                number_start.get_or_insert(i);
+            }
+            'L' | 'R' => {
+                if let Some(start) = number_start.take() {
+                    steps.push(Path::Steps(line.parse(&path[start..i])?));
+                }
+                steps.push(if c == 'L' { Path::Left } else { Path::Right });
+            }
+            _ => return Err(line.malformed(&path[i..i + c.len_utf8()], "a number, `L` or `R`")),
+        }
+    }
+    if let Some(start) = number_start {
+        steps.push(Path::Steps(line.parse(&path[start..])?));
+    }
+
+    Ok(steps)
+}
+
+pub fn part1(map: &Grid<Tile>, path: &[Path]) -> usize {
+    follow_path(map, path, |_, _| {})
+}
+
+// `on_step` gets every position and direction on the way
+fn follow_path(
+    map: &Grid<Tile>,
+    path: &[Path],
+    mut on_step: impl FnMut(Position, Direction),
+) -> usize {
+    let mut direction = Direction::Right;
+    let mut position = Position(map.row(0).iter().position(|e| *e != Tile::Nil).unwrap(), 0);
+    on_step(position, direction);
+
+    for instruction in path {
+        match *instruction {
+            Path::Steps(n) => position = walk(map, &position, direction, n, &mut on_step),
+            _ => {
+                direction = direction.change_direction(instruction);
+                on_step(position, direction);
+            }
+        };
+    }
+
+    1000 * (position.1 + 1) + 4 * (position.0 + 1) + direction as usize
+}
+
+fn walk(
+    map: &Grid<Tile>,
+    start: &Position,
+    direction: Direction,
+    mut steps: usize,
+    on_step: &mut impl FnMut(Position, Direction),
+) -> Position {
+    let (line, mut index) = match direction {
+        Up | Down => (
+            map.column(start.0).copied().collect::<Vec<Tile>>(),
+            start.1 as i32,
+        ),
+        Right | Left => (map.row(start.1).to_vec(), start.0 as i32),
+    };
+
+    let step = match direction {
+        Up | Left => -1,
+        Down | Right => 1,
+    };
+
+    let position = |index: i32| match direction {
+        Up | Down => Position(start.0, index as usize),
+        Right | Left => Position(index as usize, start.1),
+    };
+
+    while steps > 0 {
+        let mut next_index = (index + step).rem_euclid(line.len() as i32);
+
+        while line[next_index as usize] == Tile::Nil {
+            next_index = (next_index + step).rem_euclid(line.len() as i32);
+        }
+
+        index = match line[next_index as usize] {
+            Tile::Empty => next_index,
+            Tile::Wall => break,
+            _ => panic!("cant be Nil"),
+        };
+        on_step(position(index), direction);
+        steps -= 1;
+    }
+
+    position(index)
+}
+
+pub fn part2(
+    map: &Grid<Tile>,
+    path: &[Path],
+    face_size: usize,
+    switch_face: TSwitchFace,
+) -> Option<usize> {
+    follow_cube_path(map, path, face_size, switch_face, |_, _| {})
+}
+
+// `on_step` gets every position on the map and direction on the way
+fn follow_cube_path(
+    map: &Grid<Tile>,
+    path: &[Path],
+    face_size: usize,
+    switch_face: TSwitchFace,
+    mut on_step: impl FnMut(Position, Direction),
+) -> Option<usize> {
+    let cube = Cube::from_map(map, face_size)?;
+    let mut direction = Direction::Right;
+    let mut face_id = 0;
+    let mut position = Position(0, 0);
+    on_step(cube.map_position(face_id, position), direction);
+
+    for instruction in path {
+        match instruction {
+            Path::Steps(n) => {
+                (face_id, position, direction) = walk_cube(
+                    *n,
+                    &cube,
+                    (face_id, position, direction),
+                    switch_face,
+                    &mut on_step,
+                )
+            }
+            _ => {
+                direction = direction.change_direction(instruction);
+                on_step(cube.map_position(face_id, position), direction);
+            }
+        };
+    }
+
+    let face = cube.faces.get(&face_id).unwrap().start_position;
+    let x = face.0 + position.0;
+    let y = face.1 + position.1;
+    Some(1000 * y + 4 * x + (direction as usize))
+}
+
+fn walk_cube(
+    mut steps: usize,
+    cube: &Cube,
+    (mut current_face_id, mut current_position, mut direction): (usize, Position, Direction),
+    switch_face: TSwitchFace,
+    on_step: &mut impl FnMut(Position, Direction),
+) -> (usize, Position, Direction) {
+    let max_index = cube.face_size - 1;
+
+    while steps > 0 {
+        let mut temp_next_position = current_position;
+        let mut temp_face_id = current_face_id;
+        let mut temp_direction = direction;
+
+        match direction {
+            Right => {
+                if current_position.0 == max_index {
+                    (temp_face_id, temp_direction, temp_next_position) =
+                        switch_face(current_face_id, direction, current_position, max_index);
+                } else {
+                    temp_next_position.0 += 1;
+                }
+            }
+            Down => {
+                if current_position.1 == max_index {
+                    (temp_face_id, temp_direction, temp_next_position) =
+                        switch_face(current_face_id, direction, current_position, max_index);
+                } else {
+                    temp_next_position.1 += 1;
+                }
+            }
+            Left => {
+                if current_position.0 == 0 {
+                    (temp_face_id, temp_direction, temp_next_position) =
+                        switch_face(current_face_id, direction, current_position, max_index);
+                } else {
+                    temp_next_position.0 -= 1;
+                }
+            }
+            Up => {
+                if current_position.1 == 0 {
+                    (temp_face_id, temp_direction, temp_next_position) =
+                        switch_face(current_face_id, direction, current_position, max_index);
+                } else {
+                    temp_next_position.1 -= 1;
+                }
+            }
+        }
+
+        let temp_face = cube.get_face(temp_face_id);
+        match temp_face[temp_next_position.1][temp_next_position.0] {
+            Tile::Wall => break,
+            Tile::Empty => {
+                current_face_id = temp_face_id;
+                current_position = temp_next_position;
+                direction = temp_direction;
+                on_step(
+                    cube.map_position(current_face_id, current_position),
+                    direction,
+                );
+            }
+            _ => panic!("cannot be other than wall or empty"),
+        }
+
+        steps -= 1;
+    }
+    (current_face_id, current_position, direction)
+}
+
+// https://www.edumedia-sciences.com/en/media/412-nets-of-a-cube
+pub type TSwitchFace = fn(usize, Direction, Position, usize) -> (usize, Direction, Position);
+
+pub fn switch_face_main(
+    face_id: usize,
+    direction: Direction,
+    position: Position,
+    max_index: usize,
+) -> (usize, Direction, Position) {
+    // https://www.edumedia-sciences.com/en/media/412-nets-of-a-cube
+    match face_id {
+        0 => match direction {
+            Right => (1, Left, Position(0, position.1)),
+            Down => (2, Down, Position(position.0, 0)),
+            Left => (4, Up, Position(max_index - position.1, max_index)),
+            Up => (5, Up, Position(position.0, max_index)),
+        },
+        1 => match direction {
+            Right => (3, Left, Position(max_index, max_index - position.1)),
+            Down => (2, Left, Position(max_index, position.0)),
+            Left => (0, Left, Position(max_index, position.1)),
+            Up => (5, Left, Position(max_index, max_index - position.0)),
+        },
+        2 => match direction {
+            Right => (1, Up, Position(position.1, max_index)),
+            Down => (3, Down, Position(0, position.1)),
+            Left => (4, Right, Position(0, max_index - position.1)),
+            Up => (0, Up, Position(position.0, max_index)),
+        },
+        3 => match direction {
+            Right => (1, Left, Position(max_index, max_index - position.1)),
+            Down => (5, Down, Position(position.0, 0)),
+            Left => (4, Down, Position(position.0, 0)),
+            Up => (2, Up, Position(position.0, max_index)),
+        },
+        4 => match direction {
+            Right => (5, Right, Position(0, position.1)),
+            Down => (0, Right, Position(0, max_index - position.0)),
+            Left => (2, Right, Position(0, max_index - position.1)),
+            Up => (3, Right, Position(0, position.0)),
+        },
+        5 => match direction {
+            Right => (1, Down, Position(max_index - position.1, 0)),
+            Down => (0, Down, Position(position.0, 0)),
+            Left => (4, Left, Position(max_index, position.1)),
+            Up => (3, Up, Position(position.0, max_index)),
+        },
+        _ => panic!("error"),
+    }
+}
+
+pub fn switch_face_example(
+    face_id: usize,
+    direction: Direction,
+    position: Position,
+    max_index: usize,
+) -> (usize, Direction, Position) {
+    match face_id {
+        0 => match direction {
+            Right => (5, Left, Position(max_index, max_index - position.1)),
+            Down => (3, Down, Position(position.0, 0)),
+            Left => (2, Down, Position(position.1, 0)),
+            Up => (1, Down, Position(max_index - position.0, 0)),
+        },
+        1 => match direction {
+            Right => (2, Right, Position(0, position.1)),
+            Down => (4, Up, Position(max_index - position.0, max_index)),
+            Left => (5, Up, Position(max_index - position.1, max_index)),
+            Up => (0, Down, Position(position.0, max_index)),
+        },
+        2 => match direction {
+            Right => (3, Right, Position(0, position.1)),
+            Down => (4, Right, Position(0, max_index - position.0)),
+            Left => (1, Left, Position(max_index, position.1)),
+            Up => (0, Right, Position(0, position.0)),
+        },
+        3 => match direction {
+            Right => (5, Down, Position(max_index - position.1, 0)),
+            Down => (4, Down, Position(position.0, 0)),
+            Left => (2, Left, Position(max_index, position.1)),
+            Up => (0, Up, Position(position.0, max_index)),
+        },
+        4 => match direction {
+            Right => (5, Right, Position(0, position.1)),
+            Down => (1, Up, Position(max_index - position.0, max_index)),
+            Left => (2, Up, Position(max_index - position.1, max_index)),
+            Up => (3, Up, Position(position.0, max_index)),
+        },
+        5 => match direction {
+            Right => (0, Left, Position(max_index, max_index - position.1)),
+            Down => (1, Right, Position(0, max_index - position.0)),
+            Left => (4, Left, Position(max_index, position.1)),
+            Up => (3, Left, Position(max_index, max_index - position.0)),
+        },
+        _ => panic!("error"),
+    }
+}
@@ -0,0 +1,244 @@
+use crate::parse::end_of_input;
+use crate::{
+    Answer, BoundingBox, Cell, Direction8, Error, Frame, Grid, NoOptions, Part, Point2, Recorder,
+    Result, Rgb, Solution,
+};
+use log::trace;
+use std::collections::BTreeSet;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Elves;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(elves: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(run_part1(elves.clone()).into())
+    }
+
+    fn part2(elves: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(run_part2(elves.clone()).into())
+    }
+
+    fn visualize(
+        elves: &Self::Input,
+        _: &Self::Options,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        // the frames grow with the elves but never shrink, so that they do
+        // not jump around
+        let mut bounds = BoundingBox::from_points(elves).expect("parsing checks there is an elf");
+        recorder.step(|| elves_frame(elves, &bounds, 0));
+
+        let rounds = match part {
+            Part::One => Some(10),
+            Part::Two => None,
+        };
+        let (elves, rounds) = spread(elves.clone(), rounds, |round, elves| {
+            for elf in elves {
+                bounds = bounds.including(elf);
+            }
+            recorder.step(|| elves_frame(elves, &bounds, round));
+        });
+
+        let answer = match part {
+            Part::One => empty_ground(&elves),
+            Part::Two => rounds as i32,
+        };
+        Some(Ok(answer.into()))
+    }
+}
+
+pub fn run_part1(elves: Elves) -> i32 {
+    let (elves, _) = spread(elves, Some(10), |_, _| {});
+    empty_ground(&elves)
+}
+
+pub fn run_part2(elves: Elves) -> i32 {
+    let (_, rounds) = spread(elves, None, |_, _| {});
+    rounds as i32
+}
+
+// the tiles without an elf in the rectangle around them
+fn empty_ground(elves: &Elves) -> i32 {
+    let bounds = BoundingBox::from_points(elves).expect("parsing checks there is an elf");
+    bounds.area() - elves.len() as i32
+}
+
+// plays `rounds` rounds, or until no elf moves, and returns the elves and the
+// number of rounds played. `on_round` gets both after every round.
+fn spread(
+    mut elves: Elves,
+    rounds: Option<u32>,
+    mut on_round: impl FnMut(u32, &Elves),
+) -> (Elves, u32) {
+    let mut i = 0;
+    while rounds.is_none_or(|rounds| i < rounds) {
+        trace!("round {i}");
+        let (next, elf_moved) = round(i, elves);
+        elves = next;
+        i += 1;
+        on_round(i, &elves);
+        // the elves stay where they are from then on
+        if !elf_moved {
+            break;
+        }
+    }
+    (elves, i)
+}
+
+const GROUND: Cell = Cell::new('.', Rgb(40, 60, 30));
+const ELF: Cell = Cell::new('#', Rgb(120, 220, 120));
+
+fn elves_frame(elves: &Elves, bounds: &BoundingBox<Position>, round: u32) -> Frame {
+    let mut frame = Frame::new(
+        format!("round {round}, {} elves", elves.len()),
+        bounds.width() as usize,
+        bounds.height() as usize,
+        GROUND,
+    );
+    for elf in elves {
+        let cell = (
+            (elf.x - bounds.min.x) as usize,
+            (elf.y - bounds.min.y) as usize,
+        );
+        frame.draw(cell, ELF);
+    }
+    frame
+}
+
+pub type Elves = BTreeSet<Position>;
+
+pub type Position = Point2<i32>;
+
+fn elf_around(position: &Position, elves: &[Position]) -> bool {
+    position.neighbours8().any(|pos| elves.contains(&pos))
+}
+
+#[derive(Debug)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+impl Direction {
+    // the 3 positions in front of the elf, and the one it moves to
+    fn positions_to_check(&self, p: &Position) -> ([Position; 3], Position) {
+        let facing = match self {
+            Direction::North => Direction8::North,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+            Direction::East => Direction8::East,
+        };
+        let step = |direction: Direction8| p.step(direction).expect("elves stay near the origin");
+
+        (
+            [facing, facing.turn_left(), facing.turn_right()].map(step),
+            step(facing),
+        )
+    }
+
+    fn u8_to_direction(n: u32) -> Self {
+        let n = n.rem_euclid(4);
+        match n {
+            0 => Direction::North,
+            1 => Direction::South,
+            2 => Direction::West,
+            3 => Direction::East,
+            _ => panic!("wrong n"),
+        }
+    }
+    fn round_order(n: u32) -> [Self; 4] {
+        [
+            Direction::u8_to_direction(n),
+            Direction::u8_to_direction(n + 1),
+            Direction::u8_to_direction(n + 2),
+            Direction::u8_to_direction(n + 3),
+        ]
+    }
+}
+
+fn round(number: u32, elves: Elves) -> (Elves, bool) {
+    let elves = Vec::from_iter(elves.clone());
+    let mut moves = elves.clone();
+    let directions = Direction::round_order(number);
+
+    let mut elf_moved = false;
+
+    trace!("{moves:?}");
+    // Part1
+    'move_loop: for wanted_move in &mut moves {
+        trace!("move: {wanted_move:?}");
+        if !elf_around(wanted_move, &elves) {
+            continue 'move_loop;
+        }
+        elf_moved = true;
+        'direction_loop: for direction in &directions {
+            trace!("-> checking {direction:?}");
+            let (to_check, new_pos) = direction.positions_to_check(wanted_move);
+            for pos in &to_check {
+                trace!("checking {pos:?}");
+                // if there is an elf, check next direction
+                if elves.contains(pos) {
+                    trace!("there is already an elf {pos:?}");
+                    continue 'direction_loop;
+                }
+            }
+            // if no elf in sight, prepare move, and pass to the next elf
+            trace!("changing {wanted_move:?} to {new_pos:?}");
+            *wanted_move = new_pos;
+            continue 'move_loop;
+        }
+    }
+    trace!("{moves:?}");
+
+    // Part2
+    let mut final_positions = Elves::new();
+    for (i, wanted_move) in moves.clone().iter().enumerate() {
+        let slice = moves
+            .iter()
+            .enumerate()
+            .filter(|(_i, _)| i != *_i)
+            .map(|e| *e.1)
+            .collect::<Vec<Position>>();
+        if !slice.contains(wanted_move) {
+            final_positions.insert(*wanted_move);
+        } else {
+            final_positions.insert(elves[i]);
+        }
+    }
+    trace!("final_positions: {final_positions:?}");
+    (final_positions, elf_moved)
+}
+
+pub fn parse_input(input: &str) -> Result<Elves> {
+    let map = Grid::parse(
+        input,
+        |_, tile| match tile {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "`.` or `#`",
+    )?;
+    let elves: Elves = map
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((x, y), _)| Position::new(x as i32, y as i32))
+        .collect();
+
+    if elves.is_empty() {
+        return Err(Error::malformed(
+            end_of_input(input),
+            "",
+            "at least one elf `#`",
+        ));
+    }
+    Ok(elves)
+}
@@ -0,0 +1,369 @@
+use crate::parse::{end_of_input, lines, Line};
+use crate::{
+    Answer, Cell, Error, Found, Frame, Grid, NoOptions, Part, Point2, Recorder, Result, Rgb,
+    Search, SeenKeys, Solution,
+};
+use log::{debug, log_enabled, trace, Level};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Game;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(game: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        part1(game)
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("no way through the blizzards"))
+    }
+
+    fn part2(game: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        part2(game)
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("no way through the blizzards"))
+    }
+
+    // the way of the expedition is searched first, then replayed minute by
+    // minute
+    fn visualize(
+        game: &Self::Input,
+        _: &Self::Options,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        let all_blizzards = blizzards_per_minute(game);
+        let trips = match part {
+            Part::One => vec![(game.current, game.end)],
+            Part::Two => vec![
+                (game.current, game.end),
+                (game.end, game.current),
+                (game.current, game.end),
+            ],
+        };
+
+        let mut way = vec![(game.current, 0)];
+        for (from, to) in trips {
+            let minute = way.last().expect("the way starts at the entrance").1;
+            let Some(found) = route(game, &all_blizzards, from, to, minute) else {
+                return Some(Err(Error::no_solution("no way through the blizzards")));
+            };
+            way.extend(found.path.into_iter().skip(1));
+        }
+
+        for &(position, minute) in &way {
+            let blizzards = &all_blizzards[minute % all_blizzards.len()];
+            recorder
+                .step(|| valley_frame(game, blizzards, Some(position), format!("minute {minute}")));
+        }
+        Some(Ok(way
+            .last()
+            .expect("the way starts at the entrance")
+            .1
+            .into()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    current: Position,
+    end: Position,
+    max_x: u8,
+    max_y: u8,
+    directions: Vec<Direction>,
+    blizzards: Vec<Position>,
+}
+
+type Position = Point2<u8>;
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '>' => Some(Direction::Right),
+            '<' => Some(Direction::Left),
+            'v' => Some(Direction::Down),
+            '^' => Some(Direction::Up),
+            _ => None,
+        }
+    }
+
+    fn to_arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    fn shift(&self, pos: &mut Position, max: (u8, u8)) {
+        match self {
+            Direction::Up => {
+                pos.y = if pos.y == 1 { max.1 } else { pos.y - 1 };
+            }
+            Direction::Down => {
+                pos.y = if pos.y == max.1 { 1 } else { pos.y + 1 };
+            }
+            Direction::Left => {
+                pos.x = if pos.x == 0 { max.0 } else { pos.x - 1 };
+            }
+            Direction::Right => {
+                pos.x = if pos.x == max.0 { 0 } else { pos.x + 1 };
+            }
+        };
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Game> {
+    let lines: Vec<Line> = lines(input).collect();
+    let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+        return Err(Error::malformed(end_of_input(input), "", "the valley"));
+    };
+    if lines.len() < 3 || lines.len() > u8::MAX as usize {
+        return Err(last.invalid(last.text, "the valley must have between 3 and 255 rows"));
+    }
+
+    let opening = |line: &Line| -> Result<u8> {
+        let row = line.text.trim();
+        let position = row
+            .chars()
+            .position(|elt| elt == '.')
+            .filter(|&x| x > 0)
+            .ok_or_else(|| line.malformed(row, "a wall `#` with an opening `.`"))?;
+        u8::try_from(position).map_err(|e| line.invalid(row, e))
+    };
+    let start = Position::new(opening(first)? - 1, 0);
+    let end = Position::new(opening(last)? - 1, (lines.len() - 1) as u8);
+
+    let width = first.text.trim().len();
+    if !(3..=u8::MAX as usize).contains(&width) {
+        return Err(first.invalid(first.text, "the valley must have between 3 and 255 columns"));
+    }
+    let max_x = (width - 3) as u8;
+    let max_y = (lines.len() - 2) as u8;
+
+    let mut directions: Vec<Direction> = vec![];
+    let mut blizzards: Vec<Position> = vec![];
+    for (y, line) in lines[1..(lines.len() - 1)].iter().enumerate() {
+        let row = line.text.trim().trim_matches('#');
+        for (x, elt) in row.char_indices() {
+            if elt != '.' {
+                let direction = Direction::from_arrow(elt).ok_or_else(|| {
+                    line.malformed(&row[x..x + elt.len_utf8()], "`.`, `>`, `<`, `v` or `^`")
+                })?;
+                directions.push(direction);
+                blizzards.push(Position::new(x as u8, (y + 1) as u8));
+            }
+        }
+    }
+
+    Ok(Game {
+        current: start,
+        end,
+        max_x,
+        max_y,
+        directions,
+        blizzards,
+    })
+}
+
+pub fn part2(game: &Game) -> Option<usize> {
+    let all_blizzards = blizzards_per_minute(game);
+
+    let there = crossing(game, &all_blizzards, game.current, game.end, 0)?;
+    let back = crossing(game, &all_blizzards, game.end, game.current, there)?;
+    crossing(game, &all_blizzards, game.current, game.end, back)
+}
+
+pub fn part1(game: &Game) -> Option<usize> {
+    let all_blizzards = blizzards_per_minute(game);
+    crossing(game, &all_blizzards, game.current, game.end, 0)
+}
+
+// the blizzards come back to their start after a while, one list per minute
+// of that period
+fn blizzards_per_minute(game: &Game) -> Vec<Vec<Position>> {
+    let all_blizzards =
+        get_all_blizzards_positions(&game.blizzards, &game.directions, game.max_x, game.max_y);
+    debug!("all_blizzards_count: {}", all_blizzards.len());
+    all_blizzards
+}
+
+// the minute `to` is reached when leaving `from` at `minute`
+fn crossing(
+    game: &Game,
+    all_blizzards: &[Vec<Position>],
+    from: Position,
+    to: Position,
+    minute: usize,
+) -> Option<usize> {
+    route(game, all_blizzards, from, to, minute).map(|found| found.goal().1)
+}
+
+// the positions of the expedition from `minute` until it reaches `to`, with
+// their minute
+fn route(
+    game: &Game,
+    all_blizzards: &[Vec<Position>],
+    from: Position,
+    to: Position,
+    minute: usize,
+) -> Option<Found<(Position, usize), usize>> {
+    let period = all_blizzards.len();
+    let max = (game.max_x, game.max_y);
+
+    let (found, stats) = Search::new((from, minute), |&(position, minute): &(Position, usize)| {
+        let blizzards = &all_blizzards[(minute + 1) % period];
+        if log_enabled!(Level::Trace) {
+            trace!("\n{}", valley_frame(game, blizzards, None, String::new()));
+        }
+        next_positions(&position, blizzards, max, &game.end, Some(&to))
+            .into_iter()
+            .map(|next| (next, minute + 1))
+            .collect::<Vec<_>>()
+    })
+    // the blizzards are the same every `period` minutes
+    .visited(SeenKeys::new(|&(position, minute): &(Position, usize)| {
+        (position, minute % period)
+    }))
+    .bfs(|(position, _)| *position == to);
+
+    debug!("from {from:?} to {to:?}: {stats}");
+    found
+}
+
+const WALL: Cell = Cell::new('#', Rgb(110, 110, 120));
+const GROUND: Cell = Cell::new('.', Rgb(30, 40, 60));
+const BLIZZARD: Rgb = Rgb(170, 220, 255);
+const EXPEDITION: Cell = Cell::new('E', Rgb(255, 120, 60));
+
+// the valley drawn like the puzzle text, a cell with several blizzards
+// showing their number
+fn valley_frame(
+    game: &Game,
+    blizzards: &[Position],
+    expedition: Option<Position>,
+    caption: String,
+) -> Frame {
+    let (width, height) = (game.max_x as usize + 3, game.max_y as usize + 2);
+    let cell = |position: &Position| (position.x as usize + 1, position.y as usize);
+
+    let mut frame = Frame::new(caption, width, height, WALL);
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            frame.draw((x, y), GROUND);
+        }
+    }
+    frame.draw(cell(&game.current), GROUND);
+    frame.draw(cell(&game.end), GROUND);
+
+    let mut counts = Grid::new(width, height, 0);
+    for (blizzard, direction) in blizzards.iter().zip(&game.directions) {
+        let position = cell(blizzard);
+        counts[position] += 1;
+        let glyph = match counts[position] {
+            1 => direction.to_arrow(),
+            n => char::from_digit(n.min(9), 10).expect("a single digit"),
+        };
+        frame.draw(position, Cell::new(glyph, BLIZZARD));
+    }
+
+    if let Some(expedition) = expedition {
+        frame.draw(cell(&expedition), EXPEDITION);
+    }
+    frame
+}
+
+fn get_all_blizzards_positions(
+    start: &[Position],
+    directions: &[Direction],
+    max_x: u8,
+    max_y: u8,
+) -> Vec<Vec<Position>> {
+    let mut all_blizzards = vec![start.to_vec()];
+    let mut current = start.to_vec();
+
+    loop {
+        for b in 0..current.len() {
+            directions[b].shift(&mut current[b], (max_x, max_y));
+        }
+        if current == start {
+            break;
+        }
+        all_blizzards.push(current.clone());
+    }
+    all_blizzards
+}
+
+fn next_positions(
+    current: &Position,
+    blizzards: &[Position],
+    max: (u8, u8),
+    end: &Position,
+    goal: Option<&Position>,
+) -> Vec<Position> {
+    // the blizzards never reach the entrance nor the exit: waiting there is
+    // always possible
+    let out = if current == &Position::new(0, 0) {
+        Some(Position::new(0, 1))
+    } else if current == end {
+        Some(Position::new(current.x, current.y - 1))
+    } else {
+        None
+    };
+    if let Some(out) = out {
+        let mut positions = vec![*current];
+        if !blizzards.contains(&out) {
+            positions.push(out);
+        }
+        return positions;
+    }
+
+    let mut positions = vec![];
+    // left
+    if current.x > 0 {
+        positions.push(Position::new(current.x - 1, current.y));
+    }
+    // right
+    if current.x < max.0 {
+        positions.push(Position::new(current.x + 1, current.y));
+    }
+    // up
+    if current.y > 1 {
+        positions.push(Position::new(current.x, current.y - 1));
+    }
+
+    // down
+    // here we need to check if we can reach `end` position
+    if current.y < max.1 || end == &Position::new(current.x, current.y + 1) {
+        positions.push(Position::new(current.x, current.y + 1));
+    }
+
+    if let Some(goal) = goal {
+        if goal == &Position::new(current.x, current.y + 1) {
+            positions.push(Position::new(current.x, current.y + 1));
+        } else if goal == &Position::new(current.x, current.y - 1) {
+            positions.push(Position::new(current.x, current.y - 1));
+        }
+    }
+
+    // we also have the option not to move and to stay at the same spot
+    positions.push(*current);
+
+    positions
+        .iter()
+        .filter(|pos| !blizzards.contains(pos))
+        .cloned()
+        .collect()
+}
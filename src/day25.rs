@@ -0,0 +1,99 @@
+use crate::parse::lines;
+use crate::{Answer, Error, NoOptions, Result, Solution};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Snafu(String);
+
+impl Snafu {
+    /// The decimal value of the number.
+    pub fn to_number(&self) -> Result<i64> {
+        self.0
+            .chars()
+            .try_fold(0i64, |acc, n| {
+                let n = match n {
+                    '-' => -1,
+                    '=' => -2,
+                    _ => n.to_digit(3).expect("validated when parsing") as i64,
+                };
+                acc.checked_mul(5)?.checked_add(n)
+            })
+            .ok_or_else(|| Error::overflow(format!("the SNAFU number `{}`", self.0)))
+    }
+
+    /// `number` written in SNAFU. The digits going from -2 to 2, negative
+    /// numbers need no sign: -3 is `-2`.
+    pub fn to_snafu(number: i64) -> Self {
+        let mut number = number;
+        let mut snafu_number = String::new();
+
+        while number != 0 {
+            // a remainder of 3 or 4 is a digit of -2 or -1 carrying one
+            let (digit, carry) = match number.rem_euclid(5) {
+                3 => ('=', 1),
+                4 => ('-', 1),
+                digit => (char::from_digit(digit as u32, 10).unwrap(), 0),
+            };
+            snafu_number.push(digit);
+            number = number.div_euclid(5) + carry;
+        }
+        if snafu_number.is_empty() {
+            snafu_number.push('0');
+        }
+
+        Snafu(snafu_number.chars().rev().collect())
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Snafu>;
+    type Options = NoOptions;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok(part1(numbers)?.to_string().into())
+    }
+
+    // the last day only has one puzzle
+    fn part2(_: &Self::Input, _: &Self::Options) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Snafu>> {
+    lines(input)
+        .map(|line| {
+            let number = line.text.trim();
+            if number
+                .chars()
+                .all(|c| matches!(c, '=' | '-' | '0' | '1' | '2'))
+            {
+                Ok(Snafu(number.to_string()))
+            } else {
+                Err(line.malformed(number, "a SNAFU number made of `=`, `-`, `0`, `1` and `2`"))
+            }
+        })
+        .collect()
+}
+
+pub fn part1(numbers: &[Snafu]) -> Result<Snafu> {
+    let mut sum: i64 = 0;
+    for number in numbers {
+        sum = sum
+            .checked_add(number.to_number()?)
+            .ok_or_else(|| Error::overflow("the sum of the SNAFU numbers"))?;
+    }
+
+    Ok(Snafu::to_snafu(sum))
+}
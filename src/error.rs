@@ -0,0 +1,186 @@
+use std::fmt;
+use std::io;
+
+/// Where an error was found in the input. Both are 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Location of `part`, which must be a slice of `line`. Anything else
+    /// points at the start of the line.
+    pub fn of(line_number: usize, line: &str, part: &str) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + part.len() <= line.len())
+            .map(|offset| line[..offset].chars().count())
+            .unwrap_or(0);
+
+        Location {
+            line: line_number,
+            column: offset + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The input does not have the expected shape: missing field, unknown
+    /// keyword, truncated block...
+    Malformed {
+        location: Location,
+        text: String,
+        expected: String,
+    },
+    /// The input has the expected shape but a value cannot be used.
+    InvalidValue {
+        location: Location,
+        text: String,
+        reason: String,
+    },
+    /// The input was read fine but the puzzle has no answer for it.
+    NoSolution(String),
+    /// Like `NoSolution`, the reason being the error of a day, kept as the
+    /// source to be matched with `downcast_ref`.
+    Unsolvable(Box<dyn std::error::Error + Send + Sync>),
+    /// An option is unknown or does not have the expected type.
+    InvalidOption(String),
+    /// A computation does not fit in its integer type, rather than wrapping
+    /// around or panicking.
+    Overflow(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn malformed(location: Location, text: &str, expected: impl Into<String>) -> Self {
+        Error::Malformed {
+            location,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn invalid(location: Location, text: &str, reason: impl fmt::Display) -> Self {
+        Error::InvalidValue {
+            location,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> Self {
+        Error::NoSolution(reason.into())
+    }
+
+    pub fn unsolvable(source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Error::Unsolvable(Box::new(source))
+    }
+
+    pub fn invalid_option(reason: impl Into<String>) -> Self {
+        Error::InvalidOption(reason.into())
+    }
+
+    /// `what` overflowed: `the sum of the SNAFU numbers`.
+    pub fn overflow(what: impl Into<String>) -> Self {
+        Error::Overflow(what.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Malformed {
+                location,
+                text,
+                expected,
+            } if text.is_empty() => write!(f, "{location}: expected {expected}, found nothing"),
+            Error::Malformed {
+                location,
+                text,
+                expected,
+            } => write!(f, "{location}: expected {expected}, found `{text}`"),
+            Error::InvalidValue {
+                location,
+                text,
+                reason,
+            } if text.is_empty() => write!(f, "{location}: {reason}"),
+            Error::InvalidValue {
+                location,
+                text,
+                reason,
+            } => write!(f, "{location}: invalid value `{text}`: {reason}"),
+            Error::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Error::Unsolvable(source) => write!(f, "no solution: {source}"),
+            Error::InvalidOption(reason) => write!(f, "invalid option: {reason}"),
+            Error::Overflow(what) => write!(f, "overflow: {what} does not fit in the integer type"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Unsolvable(source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let line = "move 3 from 1 to x";
+        let to = line.rsplit(' ').next().unwrap();
+        assert_eq!(
+            Location::of(4, line, to),
+            Location {
+                line: 4,
+                column: 18
+            }
+        );
+        let elsewhere = String::from("x");
+        assert_eq!(
+            Location::of(4, line, &elsewhere),
+            Location { line: 4, column: 1 }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let location = Location { line: 2, column: 7 };
+        assert_eq!(
+            Error::malformed(location, "B Q", "`X`, `Y` or `Z`").to_string(),
+            "line 2, column 7: expected `X`, `Y` or `Z`, found `B Q`"
+        );
+        assert_eq!(
+            Error::invalid(location, "-3", "cannot be negative").to_string(),
+            "line 2, column 7: invalid value `-3`: cannot be negative"
+        );
+        assert_eq!(
+            Error::overflow("the sum of the SNAFU numbers").to_string(),
+            "overflow: the sum of the SNAFU numbers does not fit in the integer type"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::answers::Answers;
+use crate::error::Result;
+use crate::input::{data_dir, read_path};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Environment variable overriding the directory the fixtures are read from.
+pub const FIXTURES_DIR_VAR: &str = "AOC_FIXTURES_DIR";
+
+/// An example input and its expected answers: `dayXX/<name>.txt` and
+/// `dayXX/<name>.toml` in the fixtures directory, the answers written like
+/// the ones of `answers/`. A part left out of the answers is not checked,
+/// for the examples only given for one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub name: String,
+}
+
+impl Fixture {
+    pub fn new(day: u8, name: &str) -> Self {
+        Fixture {
+            day,
+            name: name.to_string(),
+        }
+    }
+
+    /// `exampleN`, the N-th example from the puzzle text.
+    pub fn example(day: u8, n: usize) -> Self {
+        Fixture::new(day, &format!("example{n}"))
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.path("txt")
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.path("toml")
+    }
+
+    pub fn input(&self) -> Result<String> {
+        read_path(&self.input_path())
+    }
+
+    /// No answers if the fixture has no answers file.
+    pub fn answers(&self) -> Result<Answers> {
+        Answers::load_path(&self.answers_path())
+    }
+
+    fn path(&self, extension: &str) -> PathBuf {
+        fixtures_dir()
+            .join(format!("day{:02}", self.day))
+            .join(format!("{}.{extension}", self.name))
+    }
+}
+
+/// The directory holding the fixtures: `$AOC_FIXTURES_DIR` if set, then
+/// `fixtures/` in the current directory, then `fixtures/` at the root of the
+/// crate.
+pub fn fixtures_dir() -> PathBuf {
+    data_dir(FIXTURES_DIR_VAR, "fixtures")
+}
+
+/// Every fixture of `day` that has an input, by name.
+pub fn fixtures(day: u8) -> Result<Vec<Fixture>> {
+    let dir = fixtures_dir().join(format!("day{day:02}"));
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut fixtures = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                fixtures.push(Fixture::new(day, name));
+            }
+        }
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_paths() {
+        let fixture = Fixture::example(12, 2);
+        assert_eq!(fixture.name, "example2");
+        assert!(fixture
+            .input_path()
+            .ends_with(Path::new("day12/example2.txt")));
+        assert!(fixture
+            .answers_path()
+            .ends_with(Path::new("day12/example2.toml")));
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let names: Vec<String> = fixtures(9)
+            .unwrap()
+            .into_iter()
+            .map(|fixture| fixture.name)
+            .collect();
+        assert_eq!(names, ["example1", "example2"]);
+        assert!(fixtures(26).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,884 @@
+use crate::day11;
+use crate::day25::Snafu;
+use crate::error::{Error, Result};
+use crate::options::RawOptions;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeSet, HashSet};
+
+/// A random puzzle input, with the options to solve it with when they depend
+/// on the input (the row of day 15, the faces of day 22).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub options: RawOptions,
+}
+
+impl From<String> for Generated {
+    fn from(input: String) -> Self {
+        Generated {
+            input,
+            options: RawOptions::default(),
+        }
+    }
+}
+
+impl Generated {
+    fn with_options(input: String, options: &[String]) -> Self {
+        let mut raw = RawOptions::default();
+        for option in options {
+            raw.set(option).expect("the generated options are valid");
+        }
+        Generated {
+            input,
+            options: raw,
+        }
+    }
+}
+
+/// The largest size of the random inputs of each day, solved in a few
+/// seconds in release: the grids and the searches grow much faster than
+/// their input.
+const MAX_SIZES: [usize; 25] = [
+    100000, 100000, 100000, 100000, 100000, 100000, 50000, 700, 100000, 100000, 1000, 200, 100000,
+    1000, 5000, 100, 100000, 30000, 30, 10000, 100000, 500, 40, 100, 100000,
+];
+
+/// The largest `size` [`generate`] takes for `day`, `None` if there is no
+/// such day.
+pub fn max_size(day: u8) -> Option<usize> {
+    MAX_SIZES.get((day as usize).checked_sub(1)?).copied()
+}
+
+/// A random input for `day` in the format of the puzzle, the same one for the
+/// same `seed` on every platform. `size` scales it: the number of lines or
+/// of items for most days, the side of the map for the grids. An error if
+/// there is no such day or `size` is above its [`max_size`].
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<Generated> {
+    let max = max_size(day)
+        .ok_or_else(|| Error::invalid_option(format!("there is no generator for day {day}")))?;
+    if size > max {
+        return Err(Error::invalid_option(format!(
+            "the random inputs of day {day} have a size of {max} at most, found {size}"
+        )));
+    }
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let generated = match day {
+        1 => calories(rng, size).into(),
+        2 => strategy_guide(rng, size).into(),
+        3 => rucksacks(rng, size).into(),
+        4 => section_pairs(rng, size).into(),
+        5 => crate_moves(rng, size).into(),
+        6 => datastream(rng, size).into(),
+        7 => terminal(rng, size).into(),
+        8 => tree_heights(rng, size).into(),
+        9 => rope_motions(rng, size).into(),
+        10 => cpu_program(rng, size).into(),
+        11 => monkey_notes(rng, size).into(),
+        12 => heightmap(rng, size).into(),
+        13 => packet_pairs(rng, size).into(),
+        14 => rock_paths(rng, size).into(),
+        15 => sensors(rng, size),
+        16 => valves(rng, size).into(),
+        17 => jets(rng, size).into(),
+        18 => cubes(rng, size).into(),
+        19 => blueprints(rng, size).into(),
+        20 => encrypted_file(rng, size).into(),
+        21 => monkey_jobs(rng, size).into(),
+        22 => cube_map(rng, size),
+        23 => elves(rng, size).into(),
+        24 => basin(rng, size).into(),
+        25 => snafu_numbers(rng, size).into(),
+        _ => unreachable!("day {day} has a maximum size"),
+    };
+    Ok(generated)
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// `size` elves, at least 3, carrying 1 to 10 snacks.
+fn calories(rng: &mut impl Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.gen_range(1..=10))
+                .map(|_| rng.gen_range(1000..=60000).to_string())
+                .collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+/// `size` rounds.
+fn strategy_guide(rng: &mut impl Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let opponent = *b"ABC".choose(rng).unwrap() as char;
+        let me = *b"XYZ".choose(rng).unwrap() as char;
+        format!("{opponent} {me}")
+    }))
+}
+
+/// `size` rucksacks rounded up to groups of 3. Each one has a single item in
+/// both compartments and each group a single badge.
+fn rucksacks(rng: &mut impl Rng, size: usize) -> String {
+    let mut rucksacks = vec![];
+    for _ in 0..size.div_ceil(3) {
+        let mut items: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+        items.shuffle(rng);
+        let (badge, others) = items.split_first().unwrap();
+
+        // the elves of a group have no other item in common
+        for own in others.chunks(17) {
+            let (shared, own) = own.split_first().unwrap();
+            let (first_only, second_only) = own.split_at(8);
+
+            let half = rng.gen_range(2..=12);
+            let mut first = vec![*shared, *badge];
+            first.extend((2..half).map(|_| *first_only.choose(rng).unwrap()));
+            let mut second = vec![*shared];
+            second.extend((1..half).map(|_| *second_only.choose(rng).unwrap()));
+            first.shuffle(rng);
+            second.shuffle(rng);
+
+            first.extend(second);
+            rucksacks.push(String::from_utf8(first).unwrap());
+        }
+    }
+    lines(rucksacks)
+}
+
+/// `size` pairs of sections between 1 and 99.
+fn section_pairs(rng: &mut impl Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=90);
+        format!("{start}-{}", rng.gen_range(start..=99))
+    };
+    lines((0..size).map(|_| format!("{},{}", range(), range())))
+}
+
+/// 3 to 9 stacks and `size` moves, which never empty a stack.
+fn crate_moves(rng: &mut impl Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<char>> = (0..rng.gen_range(3..=9))
+        .map(|_| {
+            (0..rng.gen_range(2..=8))
+                .map(|_| rng.gen_range('A'..='Z'))
+                .collect()
+        })
+        .collect();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut drawing: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let crates: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            crates.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+    drawing.push(numbers.join(" "));
+
+    let mut moves = vec![];
+    while moves.len() < size {
+        let from = rng.gen_range(0..stacks.len());
+        let to = rng.gen_range(0..stacks.len());
+        if from == to || stacks[from].len() < 2 {
+            continue;
+        }
+        let count = rng.gen_range(1..stacks[from].len());
+        let at = stacks[from].len() - count;
+        let moved = stacks[from].split_off(at);
+        stacks[to].extend(moved);
+        moves.push(format!("move {count} from {} to {}", from + 1, to + 1));
+    }
+
+    lines(drawing) + "\n" + &lines(moves)
+}
+
+/// `size` characters before the first 14 different ones, then `size / 2`.
+fn datastream(rng: &mut impl Rng, size: usize) -> String {
+    // 3 letters can never make a marker
+    let mut buffer: String = (0..size)
+        .map(|_| *b"abc".choose(rng).unwrap() as char)
+        .collect();
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    marker.shuffle(rng);
+    buffer.extend(&marker[..14]);
+    buffer.extend((0..size / 2).map(|_| rng.gen_range('a'..='z')));
+    buffer + "\n"
+}
+
+#[derive(Default)]
+struct Directory {
+    name: String,
+    subdirs: Vec<usize>,
+    files: Vec<(u32, String)>,
+}
+
+/// `size` directories, listed and visited depth first. The files always fit
+/// on the disk.
+fn terminal(rng: &mut impl Rng, size: usize) -> String {
+    let max_file_size = (60_000_000 / (4 * size as u32)).clamp(1, 300_000);
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut _| loop {
+        let name = word(rng, 1..=8);
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut dirs: Vec<Directory> = vec![];
+    for i in 0..size {
+        let files = (0..rng.gen_range(0..=4))
+            .map(|_| {
+                let file = match rng.gen_bool(0.5) {
+                    true => format!("{}.{}", name(rng), word(rng, 1..=3)),
+                    false => name(rng),
+                };
+                (rng.gen_range(1..=max_file_size), file)
+            })
+            .collect();
+        dirs.push(Directory {
+            name: name(rng),
+            files,
+            ..Default::default()
+        });
+        if i > 0 {
+            let parent = rng.gen_range(0..i);
+            dirs[parent].subdirs.push(i);
+        }
+    }
+
+    let mut transcript = vec!["$ cd /".to_string()];
+    list_directory(&dirs, 0, &mut transcript);
+    lines(transcript)
+}
+
+fn list_directory(dirs: &[Directory], index: usize, transcript: &mut Vec<String>) {
+    let dir = &dirs[index];
+    transcript.push("$ ls".to_string());
+    transcript.extend(dir.subdirs.iter().map(|&i| format!("dir {}", dirs[i].name)));
+    transcript.extend(
+        dir.files
+            .iter()
+            .map(|(size, name)| format!("{size} {name}")),
+    );
+    for &subdir in &dir.subdirs {
+        transcript.push(format!("$ cd {}", dirs[subdir].name));
+        list_directory(dirs, subdir, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+fn word(rng: &mut impl Rng, len: std::ops::RangeInclusive<usize>) -> String {
+    (0..rng.gen_range(len))
+        .map(|_| rng.gen_range('a'..='z'))
+        .collect()
+}
+
+/// A `size` x `size` forest.
+fn tree_heights(rng: &mut impl Rng, size: usize) -> String {
+    lines((0..size).map(|_| (0..size).map(|_| rng.gen_range('0'..='9')).collect()))
+}
+
+/// `size` motions of 1 to 19 steps.
+fn rope_motions(rng: &mut impl Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let direction = *b"RLUD".choose(rng).unwrap() as char;
+        format!("{direction} {}", rng.gen_range(1..=19))
+    }))
+}
+
+/// `size` instructions, the register staying around the 40 pixels of a row.
+fn cpu_program(rng: &mut impl Rng, size: usize) -> String {
+    let mut x = 1;
+    lines((0..size).map(|_| {
+        if rng.gen_bool(0.3) {
+            return "noop".to_string();
+        }
+        let value = loop {
+            let value = rng.gen_range(-10..=10);
+            if value != 0 && (-5..=45).contains(&(x + value)) {
+                break value;
+            }
+        };
+        x += value;
+        format!("addx {value}")
+    }))
+}
+
+/// 4 to 8 monkeys holding `size` items between them, one of them squaring
+/// the worry levels. The notes are drawn again while the worry levels of
+/// part 1 overflow, which the real inputs never do.
+fn monkey_notes(rng: &mut impl Rng, size: usize) -> String {
+    loop {
+        let notes = draw_monkey_notes(rng, size);
+        let part1 =
+            day11::parse_input(&notes).and_then(|(mut monkeys, _)| day11::part1(&mut monkeys));
+        if part1.is_ok() {
+            return notes;
+        }
+    }
+}
+
+fn draw_monkey_notes(rng: &mut impl Rng, size: usize) -> String {
+    let count = (size / 4).clamp(4, 8);
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19];
+    divisors.shuffle(rng);
+    let squaring = rng.gen_range(0..count);
+
+    let mut items: Vec<Vec<u64>> = vec![vec![]; count];
+    for i in 0..size.max(count) {
+        // every monkey starts with an item
+        let monkey = if i < count {
+            i
+        } else {
+            rng.gen_range(0..count)
+        };
+        items[monkey].push(rng.gen_range(50..=99));
+    }
+
+    let monkeys: Vec<String> = (0..count)
+        .map(|n| {
+            let items: Vec<String> = items[n].iter().map(u64::to_string).collect();
+            let operation = match n == squaring {
+                true => "* old".to_string(),
+                false if rng.gen_bool(0.5) => format!("* {}", rng.gen_range(2..=19)),
+                false => format!("+ {}", rng.gen_range(1..=8)),
+            };
+            let others: Vec<usize> = (0..count).filter(|&other| other != n).collect();
+            format!(
+                "Monkey {n}:\n  Starting items: {}\n  Operation: new = old {operation}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                items.join(", "),
+                divisors[n],
+                others.choose(rng).unwrap(),
+                others.choose(rng).unwrap(),
+            )
+        })
+        .collect();
+    monkeys.join("\n\n") + "\n"
+}
+
+/// A map `size` wide, at least 18, and half as high, at least 9. The
+/// elevation rises from `S` in the top left corner to `E` in the bottom
+/// right one, with pits anywhere but along the top row and the right column
+/// so that there is always a way up.
+fn heightmap(rng: &mut impl Rng, size: usize) -> String {
+    let width = size.max(18);
+    let height = (size / 2).max(9);
+    // neighbors are at most one letter apart as width + height - 2 >= 25
+    let steps = width + height - 2;
+
+    lines((0..height).map(|y| {
+        (0..width)
+            .map(|x| {
+                let mut elevation = (x + y) * 25 / steps;
+                if y > 0 && x < width - 1 && rng.gen_bool(0.3) {
+                    elevation = elevation.saturating_sub(rng.gen_range(1..=3));
+                }
+                match (x, y) {
+                    (0, 0) => 'S',
+                    _ if (x, y) == (width - 1, height - 1) => 'E',
+                    _ => (b'a' + elevation as u8) as char,
+                }
+            })
+            .collect()
+    }))
+}
+
+/// `size` pairs of packets nested up to 4 levels.
+fn packet_pairs(rng: &mut impl Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}", packet(rng, 3), packet(rng, 3)))
+        .collect();
+    pairs.join("\n\n") + "\n"
+}
+
+fn packet(rng: &mut impl Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.gen_range(0..=4))
+        .map(|_| match depth > 0 && rng.gen_bool(0.3) {
+            true => packet(rng, depth - 1),
+            false => rng.gen_range(0..=10).to_string(),
+        })
+        .collect();
+    format!("[{}]", values.join(","))
+}
+
+/// `size` rock paths of 2 to 5 points around x=500, below the source of the
+/// sand.
+fn rock_paths(rng: &mut impl Rng, size: usize) -> String {
+    // x stays positive, as in the real inputs
+    let half_width = (10 + size as i32 / 2).min(490);
+    let x_range = 500 - half_width..=500 + half_width;
+    let y_range = 4..=12 + size as i32 / 2;
+
+    lines((0..size).map(|_| {
+        let mut point = (
+            rng.gen_range(x_range.clone()),
+            rng.gen_range(y_range.clone()),
+        );
+        let mut points = vec![point];
+        for i in 0..rng.gen_range(1..=4) {
+            // turns at every point, so that no two points are the same
+            let length = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+            point = match i % 2 {
+                0 => (
+                    (point.0 + length).clamp(*x_range.start(), *x_range.end()),
+                    point.1,
+                ),
+                _ => (
+                    point.0,
+                    (point.1 + length).clamp(*y_range.start(), *y_range.end()),
+                ),
+            };
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+        if points.len() == 1 {
+            points.push((point.0 + 1, point.1));
+        }
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+        points.join(" -> ")
+    }))
+}
+
+/// `size` sensors in a square 20 times `size` wide, the row of part 1 being
+/// in its middle. The sensors stop just short of a hidden distress beacon,
+/// though they may leave other positions uncovered too.
+fn sensors(rng: &mut impl Rng, size: usize) -> Generated {
+    let max = 20 * size as i64;
+    let beacon = (rng.gen_range(1..max), rng.gen_range(1..max));
+
+    let mut pairs = vec![];
+    while pairs.len() < size {
+        let sensor = (rng.gen_range(0..=max), rng.gen_range(0..=max));
+        let distance = (sensor.0 - beacon.0).abs() + (sensor.1 - beacon.1).abs() - 1;
+        if distance < 1 {
+            continue;
+        }
+        let dx = rng.gen_range(-distance..=distance);
+        let dy = (distance - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        pairs.push(format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0,
+            sensor.1,
+            sensor.0 + dx,
+            sensor.1 + dy
+        ));
+    }
+
+    let options = [format!("row={}", max / 2), format!("search_max={max}")];
+    Generated::with_options(lines(pairs), &options)
+}
+
+/// `size` connected valves, at least 2 and at most 676, a third of them
+/// with a flow rate but never more than 10, so that part 2 stays tractable.
+fn valves(rng: &mut impl Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names: Vec<String> = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{a}{b}")))
+        .filter(|name| name != "AA")
+        .collect();
+    names.shuffle(rng);
+    names.truncate(count - 1);
+    names.insert(0, "AA".to_string());
+
+    // a random tree so that every valve can be reached, with a few loops
+    let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.gen_range(0..valve));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut flows = vec![0; count];
+    let mut with_flow: Vec<usize> = (1..count).collect();
+    with_flow.shuffle(rng);
+    for &valve in with_flow.iter().take((count / 3).clamp(1, 10)) {
+        flows[valve] = rng.gen_range(3..=25);
+    }
+
+    lines((0..count).map(|valve| {
+        let neighbors: Vec<&str> = tunnels[valve].iter().map(|&i| names[i].as_str()).collect();
+        let tunnels = match neighbors.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        format!(
+            "Valve {} has flow rate={}; {tunnels} {}",
+            names[valve],
+            flows[valve],
+            neighbors.join(", ")
+        )
+    }))
+}
+
+/// `size` jets.
+fn jets(rng: &mut impl Rng, size: usize) -> String {
+    let jets: String = (0..size)
+        .map(|_| if rng.gen_bool(0.5) { '<' } else { '>' })
+        .collect();
+    jets + "\n"
+}
+
+/// `size` different cubes packed in a box about 4 times their volume.
+fn cubes(rng: &mut impl Rng, size: usize) -> String {
+    let side = ((4 * size) as f64).cbrt().ceil() as u32;
+    let count = size.min((side * side * side) as usize);
+
+    let mut cubes = BTreeSet::new();
+    let mut order = vec![];
+    while cubes.len() < count {
+        let cube = (
+            rng.gen_range(1..=side),
+            rng.gen_range(1..=side),
+            rng.gen_range(1..=side),
+        );
+        if cubes.insert(cube) {
+            order.push(cube);
+        }
+    }
+    lines(order.iter().map(|(x, y, z)| format!("{x},{y},{z}")))
+}
+
+/// `size` blueprints with costs like the ones of the real inputs.
+fn blueprints(rng: &mut impl Rng, size: usize) -> String {
+    lines((1..=size).map(|id| {
+        format!(
+            "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            rng.gen_range(2..=4),
+            rng.gen_range(2..=4),
+            rng.gen_range(2..=4),
+            rng.gen_range(5..=20),
+            rng.gen_range(2..=4),
+            rng.gen_range(5..=20),
+        )
+    }))
+}
+
+/// `size` numbers, at least 3, with a single 0.
+fn encrypted_file(rng: &mut impl Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (1..size.max(3))
+        .map(|_| loop {
+            let n = rng.gen_range(-10000..=10000);
+            if n != 0 {
+                break n;
+            }
+        })
+        .collect();
+    let zero = rng.gen_range(0..=numbers.len());
+    numbers.insert(zero, 0);
+    lines(numbers.iter().map(i64::to_string))
+}
+
+/// About `size` monkeys, at least 3. The jobs are built down from the
+/// number `root` compares, so that every division is exact and `humn` has
+/// an answer in part 2.
+fn monkey_jobs(rng: &mut impl Rng, size: usize) -> String {
+    let mut jobs = MonkeyJobs::default();
+    let size = size.max(3);
+    let value = rng.gen_range(1..=1000);
+    let lhs = jobs.yell(rng, value, size / 2, true);
+    let rhs = jobs.yell(rng, value, size - 1 - size / 2, false);
+    jobs.jobs.push(format!("root: {lhs} + {rhs}"));
+    jobs.jobs.shuffle(rng);
+    lines(jobs.jobs)
+}
+
+#[derive(Default)]
+struct MonkeyJobs {
+    jobs: Vec<String>,
+    names: HashSet<String>,
+}
+
+impl MonkeyJobs {
+    fn name(&mut self, rng: &mut impl Rng) -> String {
+        loop {
+            let name = word(rng, 4..=4);
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// A monkey yelling `value` with `budget` monkeys below it, `humn`
+    /// among them if `humn`.
+    fn yell(&mut self, rng: &mut impl Rng, value: i64, budget: usize, humn: bool) -> String {
+        if budget <= 1 {
+            if humn {
+                // what humn yells in part 1, not the number part 2 looks for
+                self.jobs.push(format!("humn: {}", rng.gen_range(1..=1000)));
+                return "humn".to_string();
+            }
+            let name = self.name(rng);
+            self.jobs.push(format!("{name}: {value}"));
+            return name;
+        }
+
+        // the numbers grow going down, through `-` and `/`
+        let small = value < 1_000_000_000;
+        let factors: Vec<i64> = (2..=20.min(value / 2)).filter(|f| value % f == 0).collect();
+        let mut operators = vec![];
+        if value >= 2 {
+            operators.push('+');
+        }
+        if !factors.is_empty() {
+            operators.push('*');
+        }
+        if small || operators.is_empty() {
+            operators.extend(['-', '/']);
+        }
+
+        let (operator, lhs, rhs) = match *operators.choose(rng).unwrap() {
+            '+' => {
+                let lhs = rng.gen_range(1..value);
+                ('+', lhs, value - lhs)
+            }
+            '*' => {
+                let factor = *factors.choose(rng).unwrap();
+                ('*', value / factor, factor)
+            }
+            '-' => {
+                let rhs = rng.gen_range(1..=20);
+                ('-', value + rhs, rhs)
+            }
+            _ => {
+                let rhs = rng.gen_range(2..=5);
+                ('/', value * rhs, rhs)
+            }
+        };
+
+        // never divided by humn, which could be 0 in part 1
+        let humn_left = operator == '/' || rng.gen_bool(0.5);
+        let left_budget = rng.gen_range(1..budget);
+        let name = self.name(rng);
+        let lhs = self.yell(rng, lhs, left_budget, humn && humn_left);
+        let rhs = self.yell(rng, rhs, budget - left_budget, humn && !humn_left);
+        self.jobs.push(format!("{name}: {lhs} {operator} {rhs}"));
+        name
+    }
+}
+
+/// A cube folded like the real inputs, with faces `size` wide (at least 2),
+/// and a path of `2 * size + 1` instructions.
+fn cube_map(rng: &mut impl Rng, size: usize) -> Generated {
+    let n = size.max(2);
+    // the columns of the faces on each row of faces
+    let faces: [&[usize]; 4] = [&[1, 2], &[1], &[0, 1], &[0]];
+
+    let mut map = vec![];
+    for (face_row, columns) in faces.iter().enumerate() {
+        for y in 0..n {
+            let first = columns[0];
+            let mut row = " ".repeat(first * n);
+            for x in first * n..(columns.last().unwrap() + 1) * n {
+                let start = face_row == 0 && y == 0 && x == n;
+                row.push(if !start && rng.gen_bool(0.1) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            map.push(row);
+        }
+    }
+
+    let mut path = String::new();
+    for i in 0..2 * n + 1 {
+        if i % 2 == 0 {
+            path += &rng.gen_range(1..=2 * n).to_string();
+        } else {
+            path.push(if rng.gen_bool(0.5) { 'L' } else { 'R' });
+        }
+    }
+
+    let input = lines(map) + "\n" + &path + "\n";
+    let options = [format!("face_size={n}"), "layout=\"input\"".to_string()];
+    Generated::with_options(input, &options)
+}
+
+/// A `size` x `size` grove about half full of elves, one of them always in
+/// the top left corner.
+fn elves(rng: &mut impl Rng, size: usize) -> String {
+    lines((0..size).map(|y| {
+        (0..size)
+            .map(|x| match (x, y) == (0, 0) || rng.gen_bool(0.5) {
+                true => '#',
+                false => '.',
+            })
+            .collect()
+    }))
+}
+
+/// A valley `size` wide (3 to 253) and a quarter as high (at least 2),
+/// a third full of blizzards. No blizzard blows up or down the columns of the
+/// entrance and of the exit. The valley is drawn again while there is no way
+/// there, back and there again.
+fn basin(rng: &mut impl Rng, size: usize) -> String {
+    let width = size.clamp(3, 253);
+    let height = (size / 4).clamp(2, 253);
+
+    loop {
+        let valley: Vec<Vec<char>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|x| {
+                        let vertical = x != 0 && x != width - 1;
+                        match rng.gen_range(0..12) {
+                            0 => '>',
+                            1 => '<',
+                            2 if vertical => '^',
+                            3 if vertical => 'v',
+                            _ => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let entrance = (0, -1);
+        let exit = (width as i64 - 1, height as i64);
+        let there = crossing(&valley, entrance, exit, 0);
+        let back = there.and_then(|minute| crossing(&valley, exit, entrance, minute));
+        if back
+            .and_then(|minute| crossing(&valley, entrance, exit, minute))
+            .is_none()
+        {
+            continue;
+        }
+
+        let mut rows = vec![format!("#.{}", "#".repeat(width))];
+        rows.extend(
+            valley
+                .iter()
+                .map(|row| format!("#{}#", String::from_iter(row))),
+        );
+        rows.push(format!("{}.#", "#".repeat(width)));
+        return lines(rows);
+    }
+}
+
+/// The first minute `to` can be reached leaving `from` at `minute`, `None`
+/// if it cannot be within a few cycles of the blizzards.
+fn crossing(
+    valley: &[Vec<char>],
+    from: (i64, i64),
+    to: (i64, i64),
+    minute: usize,
+) -> Option<usize> {
+    let (width, height) = (valley[0].len() as i64, valley.len() as i64);
+    // where the blizzards reaching a position at `minute` started from
+    let free = |(x, y): (i64, i64), minute: i64| {
+        if (x, y) == from || (x, y) == to {
+            return true;
+        }
+        if !(0..width).contains(&x) || !(0..height).contains(&y) {
+            return false;
+        }
+        let row = &valley[y as usize];
+        let (x, y) = (x as usize, y as usize);
+        row[(x as i64 - minute).rem_euclid(width) as usize] != '>'
+            && row[(x as i64 + minute).rem_euclid(width) as usize] != '<'
+            && valley[(y as i64 - minute).rem_euclid(height) as usize][x] != 'v'
+            && valley[(y as i64 + minute).rem_euclid(height) as usize][x] != '^'
+    };
+
+    let mut reached = HashSet::from([from]);
+    let cycle = width * height / gcd(width, height);
+    for minute in minute as i64 + 1..=minute as i64 + 3 * cycle {
+        reached = reached
+            .iter()
+            .flat_map(|&(x, y)| [(x, y), (x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)])
+            .filter(|&position| free(position, minute))
+            .collect();
+        if reached.contains(&to) {
+            return Some(minute as usize);
+        }
+    }
+    None
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// `size` SNAFU numbers up to 10^12.
+fn snafu_numbers(rng: &mut impl Rng, size: usize) -> String {
+    lines((0..size).map(|_| Snafu::to_snafu(rng.gen_range(1..=1_000_000_000_000)).to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_day, Part};
+
+    #[test]
+    fn test_generate() {
+        for day in 1..=25 {
+            assert_eq!(
+                generate(day, 7, 12).ok(),
+                generate(day, 7, 12).ok(),
+                "day {day}"
+            );
+            assert_ne!(
+                generate(day, 7, 12).ok(),
+                generate(day, 8, 12).ok(),
+                "day {day}"
+            );
+
+            for seed in 0..3 {
+                let generated = generate(day, seed, 6).unwrap();
+                let mut options = generated.options.clone();
+                // 32 minutes take too long without optimizations
+                if day == 19 {
+                    options.set("part2_minutes=24").unwrap();
+                }
+                let parsed = get_day(day)
+                    .unwrap()
+                    .parse_with(&generated.input, &options)
+                    .unwrap_or_else(|e| panic!("day {day} seed {seed}: {e}"));
+                for part in [Part::One, Part::Two] {
+                    if let Err(e) = parsed.solve(part) {
+                        panic!("day {day} seed {seed} {part}: {e}");
+                    }
+                }
+            }
+
+            let max = max_size(day).unwrap();
+            let generated = generate(day, 7, max).unwrap();
+            if let Err(e) = get_day(day)
+                .unwrap()
+                .parse_with(&generated.input, &generated.options)
+            {
+                panic!("day {day} size {max}: {e}");
+            }
+            assert!(matches!(
+                generate(day, 7, max + 1),
+                Err(Error::InvalidOption(_))
+            ));
+        }
+        assert!(generate(26, 7, 12).is_err());
+        assert_eq!(max_size(0), None);
+    }
+}
@@ -0,0 +1,353 @@
+use crate::error::{Error, Result};
+use crate::parse::{end_of_input, lines, Line};
+use crate::point::Point2;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid, stored row by row. Cells are addressed by `(x, y)`,
+/// `x` being the column and `y` the row, `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Offsets of the 4 orthogonal neighbours: right, down, left, up.
+const NEIGHBOURS4: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// Offsets of the 8 neighbours, clockwise from the right.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+impl<T> Grid<T> {
+    /// `None` if the rows do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one row per non-blank line. `cell` gets the
+    /// position and the character of every cell, and returns `None` for the
+    /// characters it does not expect.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut((usize, usize), char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self>
+    where
+        T: Clone,
+    {
+        let lines: Vec<Line> = lines(input).collect();
+        if lines.is_empty() {
+            return Err(Error::malformed(end_of_input(input), "", "a map"));
+        }
+        Grid::from_lines(&lines, None, cell, expected)
+    }
+
+    /// Like [`Grid::parse`] on lines that were already split, rows shorter
+    /// than the longest one are filled with `padding`. Without padding every
+    /// row must have the same length.
+    pub fn from_lines(
+        lines: &[Line],
+        padding: Option<T>,
+        mut cell: impl FnMut((usize, usize), char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self>
+    where
+        T: Clone,
+    {
+        let width = lines
+            .iter()
+            .map(|line| line.text.trim_end().chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let row = line.text.trim_end();
+            for (x, (i, c)) in row.char_indices().enumerate() {
+                let value = cell((x, y), c)
+                    .ok_or_else(|| line.malformed(&row[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+            }
+
+            let missing = width - row.chars().count();
+            match &padding {
+                Some(padding) => cells.extend(std::iter::repeat_n(padding.clone(), missing)),
+                None if missing > 0 => {
+                    return Err(line.invalid(row, format!("all rows must be {width} wide")));
+                }
+                None => {}
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let offset = self.offset(position);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} out of a {} wide grid",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The orthogonal neighbours of `position` that are inside the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `position` that are inside
+    /// the grid.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &NEIGHBOURS8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let neighbour = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Rows become columns: the cell at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter clockwise: the first column, read bottom up,
+    /// becomes the first row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).rev().cloned())
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter counterclockwise: the last column becomes
+    /// the first row.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} out of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} out of a {width}x{height} grid"))
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// One line per row, the cells written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |_, c| c.to_digit(10), "a digit").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("\n123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let error = Grid::parse("12\n3x\n", |_, c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert!(Grid::parse("12\n3\n", |_, c| c.to_digit(10), "a digit").is_err());
+
+        let lines: Vec<Line> = lines("  1\n23\n").collect();
+        let padded =
+            Grid::from_lines(&lines, Some(0), |_, c| c.to_digit(10).or(Some(0)), "").unwrap();
+        assert_eq!(padded.to_string(), "001\n230");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        let mut corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.position(|&n| n > 4), Some((1, 1)));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::Result;
+use crate::fixtures::{fixtures, Fixture};
+use crate::generate::generate;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory the inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `dayXX.txt` in the input directory
+    #[default]
+    Default,
+    /// `<user>/dayXX.txt` in the input directory, for other accounts' inputs
+    User(String),
+    /// An explicit file
+    Path(PathBuf),
+    /// Standard input, `-` on the command line
+    Stdin,
+    /// `exampleN.txt` in the day's fixtures, N starting at 1
+    Example(usize),
+    /// A random input, see [`generate`](crate::generate)
+    Generated { seed: u64, size: usize },
+}
+
+impl InputSource {
+    /// `-` reads from stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self {
+            InputSource::Default => read_path(&day_path(&input_dir(), None, day)),
+            InputSource::User(user) => read_path(&day_path(&input_dir(), Some(user), day)),
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            }
+            InputSource::Example(n) => example(day, *n),
+            InputSource::Generated { seed, size } => {
+                generate(day, *seed, *size).map(|generated| generated.input)
+            }
+        }
+    }
+}
+
+/// The directory holding the inputs: `$AOC_INPUT_DIR` if set, then `inputs/`
+/// in the current directory, then `inputs/` at the root of the crate.
+pub fn input_dir() -> PathBuf {
+    data_dir(INPUT_DIR_VAR, "inputs")
+}
+
+/// `$var` if set, then `name/` in the current directory, then `name/` at the
+/// root of the crate.
+pub(crate) fn data_dir(var: &str, name: &str) -> PathBuf {
+    if let Some(dir) = env::var_os(var) {
+        return PathBuf::from(dir);
+    }
+
+    let local = PathBuf::from(name);
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(name)
+    }
+}
+
+pub(crate) fn read_path(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())).into())
+}
+
+fn day_path(dir: &Path, user: Option<&str>, day: u8) -> PathBuf {
+    let file = format!("day{day:02}.txt");
+    match user {
+        Some(user) => dir.join(user).join(file),
+        None => dir.join(file),
+    }
+}
+
+fn example(day: u8, n: usize) -> Result<String> {
+    let fixture = Fixture::example(day, n);
+    if !fixture.input_path().is_file() {
+        let available = fixtures(day)?
+            .iter()
+            .filter(|fixture| fixture.name.starts_with("example"))
+            .count();
+        let message = format!("no example {n} ({available} available)");
+        return Err(io::Error::new(io::ErrorKind::NotFound, message).into());
+    }
+    fixture.input()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_path() {
+        let dir = Path::new("inputs");
+        assert_eq!(day_path(dir, None, 7), Path::new("inputs/day07.txt"));
+        assert_eq!(
+            day_path(dir, Some("teddav"), 17),
+            Path::new("inputs/teddav/day17.txt")
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert!(example(9, 2).unwrap().starts_with("R 5\n"));
+        assert_eq!(
+            example(9, 3).unwrap_err().to_string(),
+            "no example 3 (2 available)"
+        );
+        assert!(example(9, 0).is_err());
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("day01.txt"),
+            InputSource::Path(PathBuf::from("day01.txt"))
+        );
+    }
+}
@@ -1,9 +1,263 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
 use std::fs;
-use std::io;
-use std::path::Path;
 
-pub fn read_file(day: &str) -> io::Result<String> {
-    let path = Path::new("inputs").join(format!("day{day}.txt"));
+mod answers;
+mod bench;
+mod cycle;
+mod error;
+mod fixtures;
+mod generate;
+mod grid;
+mod input;
+mod logging;
+mod options;
+mod parse;
+mod point;
+mod report;
+mod search;
+mod visual;
+pub use answers::{answers_dir, answers_path, Answers, Check, Recorded, ANSWERS_DIR_VAR};
+pub use bench::{bench_day, measure, Baseline, BenchConfig, DayBench, Stats};
+pub use cycle::{find_cycle, Cycle, CycleMethod};
+pub use error::{Error, Location, Result};
+pub use fixtures::{fixtures, fixtures_dir, Fixture, FIXTURES_DIR_VAR};
+pub use generate::{generate, max_size, Generated};
+pub use grid::Grid;
+pub use input::{input_dir, InputSource, INPUT_DIR_VAR};
+pub use logging::{init_logging, LogFilter, LOG_VAR};
+pub use options::{option_names, Config, NoOptions, RawOptions};
+pub use parse::{blocks, end_of_input, lines, parse_lines, single_line, Fields, Line, Pattern};
+pub use point::{BoundingBox, Coordinate, Corners, Direction, Direction8, Point2, Point3};
+pub use report::{input_hash, AnswerType, Report, Status};
+pub use search::{memoized, Found, NoDedup, Search, SearchStats, SeenKeys, SeenStates, Visited};
+pub use visual::{Animator, Cell, Frame, FrameExport, FrameSink, ImageFormat, Recorder, Rgb};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub fn read_file(day: &str) -> Result<String> {
+    let path = input_dir().join(format!("day{day}.txt"));
     let contents = fs::read_to_string(path)?;
     Ok(contents)
 }
+
+/// The answer to one part of a puzzle: most are numbers, a few are strings
+/// (crate labels, CRT drawings, SNAFU numbers).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+answer_from_number!(u8, u16, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// 1 or 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part1"),
+            Part::Two => write!(f, "Part2"),
+        }
+    }
+}
+
+/// A day's puzzle: the input is parsed once, then both parts run on it.
+pub trait Solution {
+    type Input;
+
+    /// The parameters of the puzzle, [`NoOptions`] for most days. The
+    /// defaults are the values of the real input.
+    type Options: Default + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input, options: &Self::Options) -> Result<Answer>;
+    fn part2(input: &Self::Input, options: &Self::Options) -> Result<Answer>;
+
+    /// Solves `part` while drawing each step of its simulation into
+    /// `recorder`. `None` for the days that are not simulations.
+    fn visualize(
+        _input: &Self::Input,
+        _options: &Self::Options,
+        _part: Part,
+        _recorder: &mut Recorder,
+    ) -> Option<Result<Answer>> {
+        None
+    }
+}
+
+/// A parsed input with its `Solution` type erased, so every day can live in
+/// the same registry.
+pub trait ParsedInput {
+    fn solve(&self, part: Part) -> Result<Answer>;
+    fn visualize(&self, part: Part, recorder: &mut Recorder) -> Option<Result<Answer>>;
+}
+
+struct Parsed<S: Solution>(S::Input, S::Options);
+
+impl<S: Solution> ParsedInput for Parsed<S> {
+    fn solve(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => S::part1(&self.0, &self.1),
+            Part::Two => S::part2(&self.0, &self.1),
+        }
+    }
+
+    fn visualize(&self, part: Part, recorder: &mut Recorder) -> Option<Result<Answer>> {
+        S::visualize(&self.0, &self.1, part, recorder)
+    }
+}
+
+fn parse_boxed<S>(input: &str, options: &RawOptions) -> Result<Box<dyn ParsedInput>>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    let options = options.typed::<S::Options>()?;
+    Ok(Box::new(Parsed::<S>(S::parse(input)?, options)))
+}
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    parse: fn(&str, &RawOptions) -> Result<Box<dyn ParsedInput>>,
+    option_names: fn() -> &'static [&'static str],
+}
+
+impl Day {
+    const fn new<S>(number: u8, title: &'static str) -> Self
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        Day {
+            number,
+            title,
+            parse: parse_boxed::<S>,
+            option_names: option_names::<S::Options>,
+        }
+    }
+
+    /// The names of the options of the day, the fields of its `Options`.
+    pub fn option_names(&self) -> &'static [&'static str] {
+        (self.option_names)()
+    }
+
+    /// Parses `input` for the default options.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn ParsedInput>> {
+        self.parse_with(input, &RawOptions::default())
+    }
+
+    /// Parses `input`, the options being checked first.
+    pub fn parse_with(&self, input: &str, options: &RawOptions) -> Result<Box<dyn ParsedInput>> {
+        (self.parse)(input, options)
+    }
+
+    pub fn read_input(&self, source: &InputSource) -> Result<String> {
+        source.read(self.number)
+    }
+}
+
+pub static DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(1, "Calorie Counting"),
+    Day::new::<day02::Day02>(2, "Rock Paper Scissors"),
+    Day::new::<day03::Day03>(3, "Rucksack Reorganization"),
+    Day::new::<day04::Day04>(4, "Camp Cleanup"),
+    Day::new::<day05::Day05>(5, "Supply Stacks"),
+    Day::new::<day06::Day06>(6, "Tuning Trouble"),
+    Day::new::<day07::Day07>(7, "No Space Left On Device"),
+    Day::new::<day08::Day08>(8, "Treetop Tree House"),
+    Day::new::<day09::Day09>(9, "Rope Bridge"),
+    Day::new::<day10::Day10>(10, "Cathode-Ray Tube"),
+    Day::new::<day11::Day11>(11, "Monkey in the Middle"),
+    Day::new::<day12::Day12>(12, "Hill Climbing Algorithm"),
+    Day::new::<day13::Day13>(13, "Distress Signal"),
+    Day::new::<day14::Day14>(14, "Regolith Reservoir"),
+    Day::new::<day15::Day15>(15, "Beacon Exclusion Zone"),
+    Day::new::<day16::Day16>(16, "Proboscidea Volcanium"),
+    Day::new::<day17::Day17>(17, "Pyroclastic Flow"),
+    Day::new::<day18::Day18>(18, "Boiling Boulders"),
+    Day::new::<day19::Day19>(19, "Not Enough Minerals"),
+    Day::new::<day20::Day20>(20, "Grove Positioning System"),
+    Day::new::<day21::Day21>(21, "Monkey Math"),
+    Day::new::<day22::Day22>(22, "Monkey Map"),
+    Day::new::<day23::Day23>(23, "Unstable Diffusion"),
+    Day::new::<day24::Day24>(24, "Blizzard Basin"),
+    Day::new::<day25::Day25>(25, "Full of Hot Air"),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,138 @@
+use crate::error::{Error, Result};
+use log::{LevelFilter, Log, Metadata, Record};
+use std::env;
+
+/// Environment variable setting the log levels, like `RUST_LOG`:
+/// `debug`, `day16=trace` or `info,day07=trace`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+const CRATE_PREFIX: &str = "adventofcode::";
+
+/// The level of every target, `info` or `day07=trace` entries separated by
+/// commas. The targets are the modules of the crate without the crate name,
+/// `day07` covering `day07` and its submodules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        LogFilter {
+            default: LevelFilter::Warn,
+            targets: vec![],
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut filter = LogFilter::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim().trim_start_matches(CRATE_PREFIX);
+                    filter
+                        .targets
+                        .push((target.to_string(), parse_level(level)?));
+                }
+                None => filter.default = parse_level(entry)?,
+            }
+        }
+        // the most specific target wins
+        filter
+            .targets
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+        Ok(filter)
+    }
+
+    /// Raises the default level to at least `level`.
+    pub fn at_least(mut self, level: LevelFilter) -> Self {
+        self.default = self.default.max(level);
+        self
+    }
+
+    pub fn level(&self, target: &str) -> LevelFilter {
+        let target = target.trim_start_matches(CRATE_PREFIX);
+        self.targets
+            .iter()
+            .find(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter> {
+    level
+        .trim()
+        .parse()
+        .map_err(|_| Error::invalid_option(format!("`{level}` is not a log level in `{LOG_VAR}`")))
+}
+
+/// Writes the log records to stderr, so that stdout only has the answers.
+struct Logger {
+    filter: LogFilter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let target = record.target().trim_start_matches(CRATE_PREFIX);
+            eprintln!("[{:<5} {target}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Logs to stderr with the levels of `$AOC_LOG`, the default level being
+/// raised by `verbosity`: 1 for `info`, 2 for `debug`, 3 for `trace`.
+/// Without either only the warnings and errors are shown.
+pub fn init_logging(verbosity: u8) -> Result<()> {
+    let spec = env::var(LOG_VAR).unwrap_or_default();
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    let filter = LogFilter::parse(&spec)?.at_least(level);
+
+    log::set_max_level(filter.max_level());
+    log::set_boxed_logger(Box::new(Logger { filter }))
+        .map_err(|_| Error::invalid_option("the logger is already set"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_filter() {
+        let filter = LogFilter::parse("info, day07=trace,adventofcode::day16=off").unwrap();
+        assert_eq!(filter.level("adventofcode::day07"), LevelFilter::Trace);
+        assert_eq!(filter.level("adventofcode::day16"), LevelFilter::Off);
+        assert_eq!(filter.level("adventofcode::day17"), LevelFilter::Info);
+        assert_eq!(filter.level("adventofcode::day070"), LevelFilter::Info);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+
+        let filter = LogFilter::parse("").unwrap().at_least(LevelFilter::Debug);
+        assert_eq!(filter.level("adventofcode::day01"), LevelFilter::Debug);
+        assert!(LogFilter::parse("day07=loud").is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use crate::answers::toml_error;
+use crate::error::{Error, Result};
+use crate::input::read_path;
+use serde::de::{self, DeserializeOwned, Deserializer, Visitor};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// `key = value` options as given by the user, before they are checked
+/// against the options of a day.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct RawOptions(toml::Table);
+
+/// Options shared by the days without parameters: any option is rejected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoOptions {}
+
+impl RawOptions {
+    /// Sets `key=value`. The value is read as TOML (`10`, `true`, `"text"`),
+    /// anything else being taken as a string.
+    pub fn set(&mut self, assignment: &str) -> Result<()> {
+        let (key, value) = parse_assignment(assignment)?;
+        self.0.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// Adds the options of `other`, which win over the ones already set.
+    pub fn merge(&mut self, other: &RawOptions) {
+        for (key, value) in &other.0 {
+            self.0.insert(key.clone(), value.clone());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The options checked against `T`, the ones not set taking their
+    /// default value.
+    pub fn typed<T: DeserializeOwned>(&self) -> Result<T> {
+        toml::Value::Table(self.0.clone())
+            .try_into()
+            .map_err(|e: toml::de::Error| Error::invalid_option(e.message()))
+    }
+}
+
+/// Options for several days: the ones of a config file and of the command
+/// line. A config file gives the options of every day in its `[dayXX]`
+/// table, the options outside of a table applying to every day that has
+/// them:
+/// ```toml
+/// [day15]
+/// row = 10
+/// search_max = 20
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    all: RawOptions,
+    days: BTreeMap<u8, RawOptions>,
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self> {
+        let table: toml::Table = toml::from_str(contents).map_err(|e| toml_error(contents, e))?;
+
+        let mut config = Config::default();
+        for (key, value) in table {
+            match (day_number(&key), value) {
+                (Some(day), toml::Value::Table(options)) => {
+                    config.days.insert(day, RawOptions(options));
+                }
+                (Some(_), _) => {
+                    return Err(Error::invalid_option(format!(
+                        "`{key}` must be a table of options"
+                    )));
+                }
+                (None, value) => {
+                    config.all.0.insert(key, value);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Config::parse(&read_path(path)?)
+            .map_err(|e| Error::invalid_option(format!("{}: {e}", path.display())))
+    }
+
+    /// Sets `key=value` for every day, or `dayXX.key=value` for one day.
+    pub fn set(&mut self, assignment: &str) -> Result<()> {
+        let (key, value) = parse_assignment(assignment)?;
+        let day_key = key
+            .split_once('.')
+            .and_then(|(day, key)| Some((day_number(day)?, key)));
+        let (options, key) = match day_key {
+            Some((day, key)) => (self.days.entry(day).or_default(), key),
+            None => (&mut self.all, key),
+        };
+        options.0.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// The options of `day`, whose options are `names`: the ones of every
+    /// day it has, its own ones winning over them. Its own unknown ones are
+    /// kept, to be rejected with the others.
+    pub fn day(&self, day: u8, names: &[&str]) -> RawOptions {
+        let mut options = RawOptions(
+            self.all
+                .0
+                .iter()
+                .filter(|(key, _)| names.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        );
+        if let Some(own) = self.days.get(&day) {
+            options.merge(own);
+        }
+        options
+    }
+
+    /// An error if an option of every day is not in `names`, the options of
+    /// the days it applies to.
+    pub fn check_shared(&self, names: &[&str]) -> Result<()> {
+        match self.all.0.keys().find(|key| !names.contains(&key.as_str())) {
+            Some(key) => Err(Error::invalid_option(format!(
+                "`{key}` is not an option of any of the days"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The fields of the options struct `T`, as its `Deserialize` implementation
+/// asks for them. Empty if `T` is not a struct.
+pub fn option_names<T: DeserializeOwned>() -> &'static [&'static str] {
+    let mut names: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut names));
+    names
+}
+
+// a deserializer that only records the fields it is asked for, and fails
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("only the fields are read"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+        byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map
+        enum identifier ignored_any
+    }
+}
+
+/// `dayXX` (or `dayX`) as a day number.
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")
+        .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        .and_then(|n| n.parse().ok())
+}
+
+fn parse_assignment(assignment: &str) -> Result<(&str, toml::Value)> {
+    let (key, value) = assignment
+        .split_once('=')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| !key.is_empty())
+        .ok_or_else(|| {
+            Error::invalid_option(format!("expected `key=value`, found `{assignment}`"))
+        })?;
+
+    let value = toml::from_str::<toml::Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()));
+    Ok((key, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Options {
+        row: i64,
+        name: String,
+    }
+
+    impl Default for Options {
+        fn default() -> Self {
+            Options {
+                row: 2000000,
+                name: "AA".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_raw_options() {
+        let mut options = RawOptions::default();
+        assert_eq!(options.typed::<Options>().unwrap(), Options::default());
+
+        options.set("row=10").unwrap();
+        options.set("name = BB").unwrap();
+        assert_eq!(
+            options.typed::<Options>().unwrap(),
+            Options {
+                row: 10,
+                name: "BB".to_string()
+            }
+        );
+
+        options.set("rows=10").unwrap();
+        let error = options.typed::<Options>().unwrap_err().to_string();
+        assert!(
+            error.starts_with("invalid option: unknown field `rows`"),
+            "{error}"
+        );
+        assert!(options.set("row").is_err());
+        assert!(RawOptions::default().typed::<NoOptions>().is_ok());
+    }
+
+    #[test]
+    fn test_config() {
+        let names = option_names::<Options>();
+        assert_eq!(names, ["row", "name"]);
+        assert!(option_names::<NoOptions>().is_empty());
+
+        let mut config = Config::parse("name = \"CC\"\n\n[day15]\nrow = 10\n").unwrap();
+        config.set("day16.name=DD").unwrap();
+        config.set("day15.row=11").unwrap();
+
+        let day15: Options = config.day(15, names).typed().unwrap();
+        assert_eq!((day15.row, day15.name.as_str()), (11, "CC"));
+        let day16: Options = config.day(16, names).typed().unwrap();
+        assert_eq!((day16.row, day16.name.as_str()), (2000000, "DD"));
+        // a day without the option does not get it
+        assert!(config.day(1, &[]).typed::<NoOptions>().is_ok());
+        assert!(config.check_shared(names).is_ok());
+        assert!(config.check_shared(&["row"]).is_err());
+
+        let error = Config::parse("[day15]\nrow = \n").unwrap_err();
+        assert!(error.to_string().starts_with("line 2, column "), "{error}");
+        assert!(Config::parse("day15 = 10\n").is_err());
+    }
+}
@@ -0,0 +1,377 @@
+use crate::error::{Error, Location, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// A line of the input with its 1-based number, so that errors can point
+/// at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// The number of the line, from 1.
+    pub number: usize,
+    /// The line without its end of line.
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Location of `part`, a slice of this line.
+    pub fn location(&self, part: &str) -> Location {
+        Location::of(self.number, self.text, part)
+    }
+
+    /// An [`Error::Malformed`] at `part`, which is not `expected`.
+    pub fn malformed(&self, part: &str, expected: impl Into<String>) -> Error {
+        Error::malformed(self.location(part), part, expected)
+    }
+
+    /// An [`Error::InvalidValue`] at `part`, for `reason`.
+    pub fn invalid(&self, part: &str, reason: impl fmt::Display) -> Error {
+        Error::invalid(self.location(part), part, reason)
+    }
+
+    /// Parses `part` of the line, reporting where it failed.
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse().map_err(|e| self.invalid(part, e))
+    }
+
+    /// The whole trimmed line parsed with `T::from_str`.
+    pub fn value<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse(self.text.trim())
+    }
+
+    /// Every signed integer of the line, whatever surrounds them: `x=-3, y=4`
+    /// gives `[-3, 4]`.
+    pub fn ints<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        integers(self.text).map(|n| self.parse(n)).collect()
+    }
+
+    /// Like [`Line::ints`], for a line holding exactly `N` integers.
+    pub fn ints_n<T, const N: usize>(&self) -> Result<[T; N]>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let text = self.text.trim();
+        self.ints()?
+            .try_into()
+            .map_err(|_| self.malformed(text, format!("{N} numbers")))
+    }
+
+    /// Splits the trimmed line in exactly `N` fields.
+    pub fn split_n<const N: usize>(&self, separator: &str, expected: &str) -> Result<[&'a str; N]> {
+        let text = self.text.trim();
+        let fields: Vec<&'a str> = text.split(separator).collect();
+        fields
+            .try_into()
+            .map_err(|_| self.malformed(text, expected))
+    }
+
+    /// The trimmed line without `prefix`.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str> {
+        let text = self.text.trim();
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.malformed(text, format!("`{prefix}...`")))
+    }
+}
+
+/// The slices of `text` that are integers, a `-` right before the digits
+/// being their sign.
+fn integers(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+
+        let start = if i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&text[start..i])
+    })
+}
+
+/// A line template where `{name}` marks a field, like
+/// `"Sensor at x={x}, y={y}"`. A field ends where the text following it in
+/// the template starts, or at the end of the line.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Text(String),
+    Field(String),
+}
+
+/// The fields of a line matched by a [`Pattern`].
+#[derive(Debug)]
+pub struct Fields<'p, 'a> {
+    line: Line<'a>,
+    values: Vec<(&'p str, &'a str)>,
+}
+
+impl Pattern {
+    /// Panics if the template is ambiguous, with two fields next to each
+    /// other, or if a `{` is not closed.
+    pub fn new(template: &str) -> Self {
+        let mut segments = vec![];
+        let mut rest = template;
+
+        while !rest.is_empty() {
+            match rest.split_once('{') {
+                Some((text, field)) => {
+                    let (name, after) = field
+                        .split_once('}')
+                        .unwrap_or_else(|| panic!("unclosed field in `{template}`"));
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(text.to_string()));
+                    }
+                    if let Some(Segment::Field(previous)) = segments.last() {
+                        panic!(
+                            "`{previous}` and `{name}` have nothing between them in `{template}`"
+                        );
+                    }
+                    segments.push(Segment::Field(name.to_string()));
+                    rest = after;
+                }
+                None => {
+                    segments.push(Segment::Text(rest.to_string()));
+                    rest = "";
+                }
+            }
+        }
+
+        Pattern { segments }
+    }
+
+    /// Matches the trimmed line, pointing at the first text that differs
+    /// from the template.
+    pub fn matches<'p, 'a>(&'p self, line: &Line<'a>) -> Result<Fields<'p, 'a>> {
+        let mut rest = line.text.trim();
+        let mut values = vec![];
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Text(text) => {
+                    rest = rest
+                        .strip_prefix(text.as_str())
+                        .ok_or_else(|| line.malformed(rest, format!("`{text}`")))?;
+                }
+                Segment::Field(name) => {
+                    let end = match self.segments.get(i + 1) {
+                        Some(Segment::Text(next)) => rest.find(next.as_str()).ok_or_else(|| {
+                            line.malformed(rest, format!("{name} followed by `{next}`"))
+                        })?,
+                        _ => rest.len(),
+                    };
+                    values.push((name.as_str(), &rest[..end]));
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(line.malformed(rest, "the end of the line"));
+        }
+        Ok(Fields {
+            line: *line,
+            values,
+        })
+    }
+}
+
+impl<'p, 'a> Fields<'p, 'a> {
+    /// The raw text of a field. Panics if the pattern has no such field.
+    pub fn str(&self, name: &str) -> &'a str {
+        self.values
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("the pattern has no field `{name}`"))
+    }
+
+    /// A field parsed with `T::from_str`, the error names the field.
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self.str(name);
+        value
+            .parse()
+            .map_err(|e| self.line.invalid(value, format!("field `{name}`: {e}")))
+    }
+}
+
+/// Location just after the last line of `input`, for what is missing.
+pub fn end_of_input(input: &str) -> Location {
+    Location {
+        line: input.lines().count() + 1,
+        column: 1,
+    }
+}
+
+/// The non-blank lines of `input`.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(i, text)| Line {
+            number: i + 1,
+            text,
+        })
+}
+
+/// Every non-blank line of `input` parsed with `T::from_str`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    lines(input).map(|line| line.value()).collect()
+}
+
+/// The only non-blank line of `input`.
+pub fn single_line<'a>(input: &'a str, expected: &str) -> Result<Line<'a>> {
+    let mut lines = lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| Error::malformed(end_of_input(input), "", expected))?;
+    match lines.next() {
+        Some(extra) => Err(extra.malformed(extra.text, "nothing after the first line")),
+        None => Ok(line),
+    }
+}
+
+/// The groups of lines of `input` separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for (i, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(block);
+                block = vec![];
+            }
+        } else {
+            block.push(Line {
+                number: i + 1,
+                text,
+            });
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = "\n1\n\n2\n";
+        let lines: Vec<(usize, &str)> = lines(input).map(|l| (l.number, l.text)).collect();
+        assert_eq!(lines, vec![(2, "1"), (4, "2")]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n1\n2\n\n\n3\n";
+        let blocks: Vec<Vec<usize>> = blocks(input)
+            .iter()
+            .map(|b| b.iter().map(|l| l.number).collect())
+            .collect();
+        assert_eq!(blocks, vec![vec![2, 3], vec![6]]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let line = Line {
+            number: 3,
+            text: "1000 abc",
+        };
+        let [_, value] = line.split_n(" ", "two fields").unwrap();
+        let error = line.parse::<u32>(value).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: invalid value `abc`: invalid digit found in string"
+        );
+        assert!(line.split_n::<3>(" ", "three fields").is_err());
+    }
+
+    #[test]
+    fn test_ints() {
+        let line = Line {
+            number: 1,
+            text: "Sensor at x=-2, y=15: closest beacon is at x=3-4",
+        };
+        assert_eq!(line.ints::<i32>().unwrap(), vec![-2, 15, 3, -4]);
+        assert_eq!(line.ints_n::<i32, 4>().unwrap(), [-2, 15, 3, -4]);
+        assert!(line.ints_n::<i32, 3>().is_err());
+        assert_eq!(
+            line.ints::<u32>().unwrap_err().to_string(),
+            "line 1, column 13: invalid value `-2`: invalid digit found in string"
+        );
+        assert_eq!(parse_lines::<i64>("1\n\n-3\n").unwrap(), vec![1, -3]);
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new("Valve {name} has flow rate={flow}; {tunnels}");
+        let line = Line {
+            number: 2,
+            text: "Valve AA has flow rate=0; tunnels lead to valves DD, II",
+        };
+        let fields = pattern.matches(&line).unwrap();
+        assert_eq!(fields.str("name"), "AA");
+        assert_eq!(fields.get::<u32>("flow").unwrap(), 0);
+        assert_eq!(fields.str("tunnels"), "tunnels lead to valves DD, II");
+
+        let line = Line {
+            number: 2,
+            text: "Valve AA has flow rate=x; tunnel leads to valve BB",
+        };
+        let fields = pattern.matches(&line).unwrap();
+        assert_eq!(
+            fields.get::<u32>("flow").unwrap_err().to_string(),
+            "line 2, column 24: invalid value `x`: field `flow`: invalid digit found in string"
+        );
+
+        let line = Line {
+            number: 2,
+            text: "Valve AA has a flow rate=0; tunnel leads to valve BB",
+        };
+        assert_eq!(
+            pattern.matches(&line).unwrap_err().to_string(),
+            "line 2, column 7: expected name followed by ` has flow rate=`, found `AA has a flow rate=0; tunnel leads to valve BB`"
+        );
+    }
+}
@@ -0,0 +1,507 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types points can be made of. Moving a point uses checked
+/// arithmetic, so that the neighbours of `(0, 0)` over unsigned coordinates
+/// simply leave out the negative ones.
+pub trait Coordinate:
+    Copy + Ord + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// `|self - rhs|`, which cannot underflow on unsigned types.
+    fn abs_diff(self, rhs: Self) -> Self {
+        if self > rhs {
+            self - rhs
+        } else {
+            rhs - self
+        }
+    }
+
+    /// `self + delta` with `delta` in `-1..=1`.
+    fn offset(self, delta: i8) -> Option<Self> {
+        match delta {
+            0 => Some(self),
+            1.. => self.checked_add(Self::ONE),
+            _ => self.checked_sub(Self::ONE),
+        }
+    }
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The 4 orthogonal directions. `y` grows downwards, like the lines of the
+/// input, so `Up` decreases it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// `(dx, dy)` of a step in this direction.
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
+
+/// The 8 compass directions, `North` being `Direction::Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from `North`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The next point in `direction`, `None` if it overflows `T`.
+    pub fn step(&self, direction: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = direction.into().delta();
+        Some(Point2::new(self.x.offset(dx)?, self.y.offset(dy)?))
+    }
+
+    /// The orthogonal neighbours, clockwise from the one above.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| point.step(d))
+    }
+
+    /// The orthogonal and diagonal neighbours, clockwise from the one above.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| point.step(d))
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours6(&self) -> impl Iterator<Item = Self> {
+        const DELTAS: [(i8, i8, i8); 6] = [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ];
+        let point = *self;
+        DELTAS
+            .into_iter()
+            .filter_map(move |delta| point.offset(delta))
+    }
+
+    /// The 26 points sharing a face, an edge or a corner with this one.
+    pub fn neighbours26(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter(|&delta| delta != (0, 0, 0))
+            .filter_map(move |delta| point.offset(delta))
+    }
+
+    fn offset(&self, (dx, dy, dz): (i8, i8, i8)) -> Option<Self> {
+        Some(Point3::new(
+            self.x.offset(dx)?,
+            self.y.offset(dy)?,
+            self.z.offset(dz)?,
+        ))
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $point { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $point { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $point { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// The smallest box holding a set of points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<P> {
+    pub min: P,
+    pub max: P,
+}
+
+/// Points a [`BoundingBox`] can be made of.
+pub trait Corners: Copy {
+    /// The smallest coordinates of both points on every axis.
+    fn lower(&self, other: &Self) -> Self;
+    /// The largest coordinates of both points on every axis.
+    fn upper(&self, other: &Self) -> Self;
+    /// Whether every coordinate is between the ones of `min` and `max`.
+    fn within(&self, min: &Self, max: &Self) -> bool;
+}
+
+impl<T: Coordinate> Corners for Point2<T> {
+    fn lower(&self, other: &Self) -> Self {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn upper(&self, other: &Self) -> Self {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    fn within(&self, min: &Self, max: &Self) -> bool {
+        (min.x..=max.x).contains(&self.x) && (min.y..=max.y).contains(&self.y)
+    }
+}
+
+impl<T: Coordinate> Corners for Point3<T> {
+    fn lower(&self, other: &Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    fn upper(&self, other: &Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    fn within(&self, min: &Self, max: &Self) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl<P: Corners> BoundingBox<P> {
+    /// `None` without points.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a P>) -> Option<Self>
+    where
+        P: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, point| bounds.including(point),
+        ))
+    }
+
+    pub fn including(self, point: &P) -> Self {
+        BoundingBox {
+            min: self.min.lower(point),
+            max: self.max.upper(point),
+        }
+    }
+
+    pub fn contains(&self, point: &P) -> bool {
+        point.within(&self.min, &self.max)
+    }
+}
+
+impl<T: Coordinate> BoundingBox<Point2<T>> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+}
+
+impl<T: Coordinate> BoundingBox<Point3<T>> {
+    /// The same box grown by `margin` on every side.
+    pub fn expanded(&self, margin: T) -> Self {
+        let margin = Point3::new(margin, margin, margin);
+        BoundingBox {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let a = Point2::new(2, -3);
+        let b = Point2::new(-1, 5);
+        assert_eq!(a + b, Point2::new(1, 2));
+        assert_eq!(a - b, Point2::new(3, -8));
+        assert_eq!(a * 2, Point2::new(4, -6));
+        assert_eq!(-a, Point2::new(-2, 3));
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 8);
+
+        let c = Point3::new(1_u32, 2, 3);
+        assert_eq!(c.manhattan(&Point3::new(3, 0, 3)), 4);
+        assert_eq!(c + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Point2::new(0_usize, 0);
+        assert_eq!(
+            origin.neighbours4().collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(origin.neighbours8().count(), 3);
+        assert_eq!(Point2::new(0_i32, 0).neighbours8().count(), 8);
+        assert_eq!(Point3::new(0_i32, 0, 0).neighbours6().count(), 6);
+        assert_eq!(Point3::new(0_i32, 0, 0).neighbours26().count(), 26);
+        assert_eq!(Point3::new(0_u8, 0, 0).neighbours26().count(), 7);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::West);
+        assert_eq!(
+            Point2::new(3, 3).step(Direction8::SouthWest),
+            Some(Point2::new(2, 4))
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(2, 3), Point2::new(-1, 5), Point2::new(0, 0)];
+        let bounds = BoundingBox::from_points(&points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-1, 0));
+        assert_eq!(bounds.max, Point2::new(2, 5));
+        assert_eq!((bounds.width(), bounds.height(), bounds.area()), (4, 6, 24));
+        assert!(bounds.contains(&Point2::new(0, 4)));
+        assert!(!bounds.contains(&Point2::new(3, 4)));
+        assert!(BoundingBox::<Point2<i32>>::from_points(&[]).is_none());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Answer, Check, Day, Part};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::time::Duration;
+
+/// How a part went: solved or not, and against the recorded answer when
+/// checking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Pass,
+    Fail,
+    Missing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnswerType {
+    Number,
+    Text,
+}
+
+/// The outcome of one part, for the `--json` output of `aoc`. Every field is
+/// always written, `null` when it does not apply:
+/// ```json
+/// {"day":10,"title":"Cathode-Ray Tube","part":1,"status":"pass","answer":13140,
+///  "type":"number","expected":"13140","error":null,"elapsed_ms":0.02,
+///  "input_hash":"4f2c…"}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    #[serde(rename = "type")]
+    pub answer_type: Option<AnswerType>,
+    /// The recorded answer, when checking.
+    pub expected: Option<String>,
+    pub error: Option<String>,
+    /// Time taken by the part, without the parsing.
+    pub elapsed_ms: Option<f64>,
+    /// SHA-256 of the input, to tell which input an answer is for.
+    pub input_hash: Option<String>,
+}
+
+impl Report {
+    /// A part that failed before it could run, `status` being `error`.
+    pub fn new(day: &Day, part: Part, input_hash: Option<String>) -> Self {
+        Report {
+            day: day.number,
+            title: day.title,
+            part: part.number(),
+            status: Status::Error,
+            answer: None,
+            answer_type: None,
+            expected: None,
+            error: None,
+            elapsed_ms: None,
+            input_hash,
+        }
+    }
+
+    pub fn solved(self, answer: Answer, elapsed: Duration) -> Self {
+        let answer_type = match answer {
+            Answer::Number(_) => AnswerType::Number,
+            Answer::Text(_) => AnswerType::Text,
+        };
+        Report {
+            status: Status::Ok,
+            answer: Some(answer),
+            answer_type: Some(answer_type),
+            elapsed_ms: Some(elapsed.as_secs_f64() * 1000.0),
+            ..self
+        }
+    }
+
+    pub fn failed(self, error: impl fmt::Display) -> Self {
+        Report {
+            status: Status::Error,
+            error: Some(error.to_string()),
+            ..self
+        }
+    }
+
+    /// The solved part compared with the recorded answer.
+    pub fn checked(self, check: &Check) -> Self {
+        match check {
+            Check::Pass => Report {
+                status: Status::Pass,
+                expected: self.answer.as_ref().map(Answer::to_string),
+                ..self
+            },
+            Check::Fail { expected } => Report {
+                status: Status::Fail,
+                expected: Some(expected.to_string()),
+                ..self
+            },
+            Check::Missing => Report {
+                status: Status::Missing,
+                ..self
+            },
+        }
+    }
+}
+
+/// The SHA-256 of an input, in hexadecimal.
+pub fn input_hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_day, Recorded};
+
+    #[test]
+    fn test_report() {
+        let day = get_day(10).unwrap();
+        let report = Report::new(day, Part::One, Some(input_hash("")))
+            .solved(Answer::Number(13140), Duration::from_millis(2))
+            .checked(&Check::Fail {
+                expected: Recorded::Number(13141),
+            });
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["status"], "fail");
+        assert_eq!(json["answer"], 13140);
+        assert_eq!(json["type"], "number");
+        assert_eq!(json["expected"], "13141");
+        assert_eq!(json["error"], serde_json::Value::Null);
+        assert_eq!(json["elapsed_ms"], 2.0);
+        assert_eq!(
+            json["input_hash"],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let report = Report::new(day, Part::Two, None).failed("no solution");
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], serde_json::Value::Null);
+    }
+}
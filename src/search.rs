@@ -0,0 +1,447 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Counters of a search, to compare the algorithms and the puzzles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// Successors generated, the ones already seen included.
+    pub generated: usize,
+    /// Most states waiting in the frontier at once, the deepest recursion
+    /// for [`memoized`].
+    pub peak_frontier: usize,
+    /// States dropped because they were already seen, the cached values
+    /// reused for [`memoized`].
+    pub dedup_hits: usize,
+}
+
+impl fmt::Display for SearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} expanded, {} generated, peak frontier {}, {} dedup hits",
+            self.expanded, self.generated, self.peak_frontier, self.dedup_hits
+        )
+    }
+}
+
+/// Remembers the states already seen, so that each one is expanded once.
+pub trait Visited<S> {
+    /// Marks `state` as seen, `false` if it already was.
+    fn insert(&mut self, state: &S) -> bool;
+}
+
+/// The states themselves are remembered, the default.
+#[derive(Debug, Clone)]
+pub struct SeenStates<S>(HashSet<S>);
+
+impl<S> Default for SeenStates<S> {
+    fn default() -> Self {
+        SeenStates(HashSet::new())
+    }
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> for SeenStates<S> {
+    fn insert(&mut self, state: &S) -> bool {
+        !self.0.contains(state) && self.0.insert(state.clone())
+    }
+}
+
+/// A key of the states is remembered, the states with the same key being
+/// the same: a position and the time modulo a period, a state without the
+/// history it carries...
+pub struct SeenKeys<K, F> {
+    keys: HashSet<K>,
+    key: F,
+}
+
+impl<K, F> SeenKeys<K, F> {
+    pub fn new(key: F) -> Self {
+        SeenKeys {
+            keys: HashSet::new(),
+            key,
+        }
+    }
+}
+
+impl<S, K: Eq + Hash, F: FnMut(&S) -> K> Visited<S> for SeenKeys<K, F> {
+    fn insert(&mut self, state: &S) -> bool {
+        self.keys.insert((self.key)(state))
+    }
+}
+
+/// Nothing is remembered, for the state spaces that are trees.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoDedup;
+
+impl<S> Visited<S> for NoDedup {
+    fn insert(&mut self, _: &S) -> bool {
+        true
+    }
+}
+
+/// A path found by a search, from the start to the goal included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub path: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Found<S, C> {
+    pub fn goal(&self) -> &S {
+        self.path.last().expect("a path holds at least the start")
+    }
+}
+
+/// The states reached by a search with the state they were reached from,
+/// to rebuild the paths.
+struct Nodes<S>(Vec<(S, Option<usize>)>);
+
+impl<S: Clone> Nodes<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.0.push((state, parent));
+        self.0.len() - 1
+    }
+
+    fn state(&self, index: usize) -> &S {
+        &self.0[index].0
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.state(index).clone()];
+        while let Some(parent) = self.0[index].1 {
+            path.push(self.state(parent).clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// A search from `start` through the states given by `successors`, plain
+/// states for the breadth and depth first searches, `(state, cost)` pairs
+/// for Dijkstra and A*:
+/// ```
+/// use adventofcode::Search;
+///
+/// let (found, stats) = Search::new(1, |&n: &u32| [n + 1, n * 2]).bfs(|&n| n == 10);
+/// assert_eq!(found.unwrap().path, [1, 2, 4, 5, 10]);
+/// assert_eq!(stats.expanded, 9);
+/// ```
+pub struct Search<S, F, V> {
+    start: S,
+    successors: F,
+    visited: V,
+}
+
+impl<S: Clone + Eq + Hash, F> Search<S, F, SeenStates<S>> {
+    pub fn new(start: S, successors: F) -> Self {
+        Search {
+            start,
+            successors,
+            visited: SeenStates::default(),
+        }
+    }
+}
+
+impl<S: Clone, F, V: Visited<S>> Search<S, F, V> {
+    /// Uses another strategy to remember the states already seen.
+    pub fn visited<W: Visited<S>>(self, visited: W) -> Search<S, F, W> {
+        Search {
+            start: self.start,
+            successors: self.successors,
+            visited,
+        }
+    }
+
+    /// The shortest path to a goal, in number of steps.
+    pub fn bfs<I>(self, is_goal: impl FnMut(&S) -> bool) -> (Option<Found<S, usize>>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+    {
+        self.uninformed(is_goal, false)
+    }
+
+    /// The first path found going deep first, not the shortest one.
+    pub fn dfs<I>(self, is_goal: impl FnMut(&S) -> bool) -> (Option<Found<S, usize>>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+    {
+        self.uninformed(is_goal, true)
+    }
+
+    fn uninformed<I>(
+        mut self,
+        mut is_goal: impl FnMut(&S) -> bool,
+        depth_first: bool,
+    ) -> (Option<Found<S, usize>>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+    {
+        let mut stats = SearchStats::default();
+        let mut nodes = Nodes(vec![]);
+        self.visited.insert(&self.start);
+        let mut frontier = VecDeque::from([(nodes.push(self.start, None), 0)]);
+
+        loop {
+            let next = match depth_first {
+                true => frontier.pop_back(),
+                false => frontier.pop_front(),
+            };
+            let Some((index, steps)) = next else {
+                return (None, stats);
+            };
+            if is_goal(nodes.state(index)) {
+                let path = nodes.path(index);
+                return (Some(Found { path, cost: steps }), stats);
+            }
+
+            stats.expanded += 1;
+            for next in (self.successors)(nodes.state(index)) {
+                stats.generated += 1;
+                if self.visited.insert(&next) {
+                    frontier.push_back((nodes.push(next, Some(index)), steps + 1));
+                } else {
+                    stats.dedup_hits += 1;
+                }
+            }
+            stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+        }
+    }
+
+    /// The cheapest path to a goal.
+    pub fn dijkstra<C, I>(
+        self,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> (Option<Found<S, C>>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        C: Copy + Ord + Default + Add<Output = C>,
+    {
+        self.astar(|_| C::default(), is_goal)
+    }
+
+    /// The cheapest path to a goal, guided by `heuristic`: an estimate of the
+    /// cost left that must never be too high.
+    pub fn astar<C, I>(
+        mut self,
+        mut heuristic: impl FnMut(&S) -> C,
+        mut is_goal: impl FnMut(&S) -> bool,
+    ) -> (Option<Found<S, C>>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        C: Copy + Ord + Default + Add<Output = C>,
+    {
+        let mut stats = SearchStats::default();
+        let mut nodes = Nodes(vec![]);
+        let mut costs = vec![C::default()];
+        let estimate = heuristic(&self.start);
+        let mut frontier = BinaryHeap::from([Reverse((estimate, nodes.push(self.start, None)))]);
+
+        // a state can wait several times in the frontier, only its cheapest
+        // occurrence is expanded
+        while let Some(Reverse((_, index))) = frontier.pop() {
+            if !self.visited.insert(nodes.state(index)) {
+                stats.dedup_hits += 1;
+                continue;
+            }
+            if is_goal(nodes.state(index)) {
+                let path = nodes.path(index);
+                return (
+                    Some(Found {
+                        path,
+                        cost: costs[index],
+                    }),
+                    stats,
+                );
+            }
+
+            stats.expanded += 1;
+            for (next, cost) in (self.successors)(nodes.state(index)) {
+                stats.generated += 1;
+                let cost = costs[index] + cost;
+                let estimate = cost + heuristic(&next);
+                frontier.push(Reverse((estimate, nodes.push(next, Some(index)))));
+                costs.push(cost);
+            }
+            stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+        }
+        (None, stats)
+    }
+
+    /// Visits every state reachable from the start, breadth first, without
+    /// keeping them.
+    pub fn for_each<I>(mut self, mut visit: impl FnMut(&S)) -> SearchStats
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+    {
+        let mut stats = SearchStats::default();
+        self.visited.insert(&self.start);
+        let mut frontier = VecDeque::from([self.start]);
+
+        while let Some(state) = frontier.pop_front() {
+            visit(&state);
+
+            stats.expanded += 1;
+            for next in (self.successors)(&state) {
+                stats.generated += 1;
+                if self.visited.insert(&next) {
+                    frontier.push_back(next);
+                } else {
+                    stats.dedup_hits += 1;
+                }
+            }
+            stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+        }
+        stats
+    }
+
+    /// Every state reachable from the start, the start included, breadth
+    /// first: a flood fill.
+    pub fn reachable<I>(self) -> (Vec<S>, SearchStats)
+    where
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+    {
+        let mut states = vec![];
+        let stats = self.for_each(|state| states.push(state.clone()));
+        (states, stats)
+    }
+}
+
+/// The value of `start`, computed depth first by `value`, which is given
+/// the state and a callback for the value of the states it depends on.
+/// Each state is computed once, the state space must have no cycles.
+pub fn memoized<S, T, F>(start: &S, value: F) -> (T, SearchStats)
+where
+    S: Clone + Eq + Hash,
+    T: Clone,
+    F: Fn(&S, &mut dyn FnMut(&S) -> T) -> T,
+{
+    let mut memo = Memo {
+        cache: HashMap::new(),
+        stats: SearchStats::default(),
+        depth: 0,
+    };
+    let result = memo.get(start, &value);
+    (result, memo.stats)
+}
+
+struct Memo<S, T> {
+    cache: HashMap<S, T>,
+    stats: SearchStats,
+    depth: usize,
+}
+
+impl<S: Clone + Eq + Hash, T: Clone> Memo<S, T> {
+    fn get<F>(&mut self, state: &S, value: &F) -> T
+    where
+        F: Fn(&S, &mut dyn FnMut(&S) -> T) -> T,
+    {
+        if let Some(result) = self.cache.get(state) {
+            self.stats.dedup_hits += 1;
+            return result.clone();
+        }
+
+        self.stats.expanded += 1;
+        self.depth += 1;
+        self.stats.peak_frontier = self.stats.peak_frontier.max(self.depth);
+        let result = value(state, &mut |next| {
+            self.stats.generated += 1;
+            self.get(next, value)
+        });
+        self.depth -= 1;
+
+        self.cache.insert(state.clone(), result.clone());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 5x5 grid with a wall on x = 2, open at y = 4
+    fn neighbours(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|&(x, y)| x != 2 || y == 4)
+            .collect()
+    }
+
+    #[test]
+    fn test_searches() {
+        let goal = |p: &(i32, i32)| *p == (4, 0);
+
+        let (found, stats) = Search::new((0, 0), neighbours).bfs(goal);
+        let found = found.unwrap();
+        assert_eq!(found.cost, 12);
+        assert_eq!(found.path.len(), 13);
+        assert_eq!((found.path[0], *found.goal()), ((0, 0), (4, 0)));
+        assert!(stats.dedup_hits > 0 && stats.expanded <= 21);
+
+        let (found, _) = Search::new((0, 0), neighbours).dfs(goal);
+        assert!(found.unwrap().cost >= 12);
+
+        let weighted = |p: &(i32, i32)| neighbours(p).into_iter().map(|n| (n, 1 + n.1));
+        let (dijkstra, dijkstra_stats) = Search::new((0, 0), weighted).dijkstra(goal);
+        let manhattan = |p: &(i32, i32)| (4 - p.0).abs() + p.1.abs();
+        let (astar, astar_stats) = Search::new((0, 0), weighted).astar(manhattan, goal);
+        assert_eq!(dijkstra.as_ref().unwrap().cost, astar.unwrap().cost);
+        // down x = 1 and up x = 3, the cost of a step being 1 + its row
+        assert_eq!(dijkstra.unwrap().cost, 36);
+        assert!(astar_stats.expanded <= dijkstra_stats.expanded);
+
+        let (unreachable, _) = Search::new((0, 0), neighbours).bfs(|p| *p == (9, 9));
+        assert_eq!(unreachable, None);
+    }
+
+    #[test]
+    fn test_visited() {
+        let (states, stats) = Search::new((0, 0), neighbours).reachable();
+        assert_eq!(states.len(), 21);
+        assert_eq!(stats.expanded, 21);
+
+        // only the rows are remembered: one state per row
+        let (states, _) = Search::new((0, 0), neighbours)
+            .visited(SeenKeys::new(|p: &(i32, i32)| p.1))
+            .reachable();
+        assert_eq!(states.len(), 5);
+
+        // a binary tree of depth 3
+        let children = |n: &u32| {
+            if *n < 8 {
+                vec![2 * n, 2 * n + 1]
+            } else {
+                vec![]
+            }
+        };
+        let (states, stats) = Search::new(1, children).visited(NoDedup).reachable();
+        assert_eq!(states.len(), 15);
+        assert_eq!(stats.dedup_hits, 0);
+    }
+
+    #[test]
+    fn test_memoized() {
+        // the number of paths to the bottom right corner of a 16x16 grid
+        let (paths, stats) = memoized(&(0u64, 0u64), |&(x, y), paths| match (x, y) {
+            (16, _) | (_, 16) => 1u64,
+            _ => paths(&(x + 1, y)) + paths(&(x, y + 1)),
+        });
+        assert_eq!(paths, 601080390);
+        assert_eq!(stats.expanded, 17 * 17 - 1);
+        assert_eq!(stats.peak_frontier, 32);
+        assert!(stats.dedup_hits > 0);
+    }
+}
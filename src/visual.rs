@@ -0,0 +1,339 @@
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// A cell of a frame: its character in the terminal and the color of its
+/// square in the images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.glyph)
+    }
+}
+
+/// One step of a simulation, drawn as a grid of cells with a caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    /// A `width` x `height` frame filled with `background`.
+    pub fn new(caption: impl Into<String>, width: usize, height: usize, background: Cell) -> Self {
+        Frame {
+            caption: caption.into(),
+            cells: Grid::new(width, height, background),
+        }
+    }
+
+    /// Draws `cell` at `(x, y)`, nothing if it is out of the frame.
+    pub fn draw(&mut self, (x, y): (usize, usize), cell: Cell) {
+        if let Some(target) = self.cells.get_mut((x, y)) {
+            *target = cell;
+        }
+    }
+}
+
+/// The characters only, like the puzzle text.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+/// Where the frames of a simulation go.
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Frame) -> Result<()>;
+}
+
+/// Collects the steps of a simulation. The frame of a step is only drawn
+/// when it is kept, one step in `every`, so that a long simulation does not
+/// pay for the frames nobody sees.
+pub struct Recorder<'a> {
+    sink: &'a mut dyn FrameSink,
+    every: usize,
+    steps: usize,
+    frames: usize,
+    error: Option<Error>,
+}
+
+impl<'a> Recorder<'a> {
+    pub fn new(sink: &'a mut dyn FrameSink, every: usize) -> Self {
+        Recorder {
+            sink,
+            every: every.max(1),
+            steps: 0,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// A step of the simulation, `render` drawing it. The sink is not called
+    /// anymore once it failed.
+    pub fn step(&mut self, render: impl FnOnce() -> Frame) {
+        if self.error.is_none() && self.steps.is_multiple_of(self.every) {
+            match self.sink.frame(&render()) {
+                Ok(()) => self.frames += 1,
+                Err(e) => self.error = Some(e),
+            }
+        }
+        self.steps += 1;
+    }
+
+    /// The number of frames drawn, or the first error of the sink.
+    pub fn finish(self) -> Result<usize> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.frames),
+        }
+    }
+}
+
+/// Plays the frames in a terminal with ANSI escape codes, `fps` frames per
+/// second at most. The cursor is hidden while playing.
+pub struct Animator<W: Write> {
+    out: W,
+    delay: Duration,
+    last: Option<Instant>,
+}
+
+impl<W: Write> Animator<W> {
+    /// No delay between the frames if `fps` is not positive.
+    pub fn new(out: W, fps: f64) -> Self {
+        let delay = match fps > 0.0 {
+            true => Duration::from_secs_f64(1.0 / fps),
+            false => Duration::ZERO,
+        };
+        Animator {
+            out,
+            delay,
+            last: None,
+        }
+    }
+}
+
+impl<W: Write> FrameSink for Animator<W> {
+    fn frame(&mut self, frame: &Frame) -> Result<()> {
+        // the whole frame is written at once so that it does not flicker
+        let mut screen = String::from("\x1b[?25l\x1b[H\x1b[2J");
+        screen.push_str(&frame.caption);
+        for row in frame.cells.rows() {
+            screen.push('\n');
+            let mut color = None;
+            for cell in row {
+                if color != Some(cell.color) {
+                    let Rgb(r, g, b) = cell.color;
+                    screen.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    color = Some(cell.color);
+                }
+                screen.push(cell.glyph);
+            }
+            screen.push_str("\x1b[0m");
+        }
+        screen.push('\n');
+
+        if let Some(last) = self.last {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+        self.out.write_all(screen.as_bytes())?;
+        self.out.flush()?;
+        self.last = Some(Instant::now());
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for Animator<W> {
+    fn drop(&mut self) {
+        let _ = write!(self.out, "\x1b[?25h");
+        let _ = self.out.flush();
+    }
+}
+
+/// The image files [`FrameExport`] writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary PPM: no compression, readable by most tools.
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!(
+                "`{s}` is not an image format, expected `ppm` or `png`"
+            )),
+        }
+    }
+}
+
+/// Writes every frame as an image, `<prefix>-00000.ppm` and so on in `dir`,
+/// each cell being a `scale` pixels wide square. The captions are not drawn.
+pub struct FrameExport {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    scale: usize,
+    count: usize,
+}
+
+impl FrameExport {
+    /// Creates `dir` if needed.
+    pub fn new(dir: &Path, prefix: &str, format: ImageFormat, scale: usize) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(FrameExport {
+            dir: dir.to_path_buf(),
+            prefix: prefix.to_string(),
+            format,
+            scale: scale.max(1),
+            count: 0,
+        })
+    }
+
+    /// The path of the next frame.
+    pub fn next_path(&self) -> PathBuf {
+        self.dir.join(format!(
+            "{}-{:05}.{}",
+            self.prefix,
+            self.count,
+            self.format.extension()
+        ))
+    }
+}
+
+impl FrameSink for FrameExport {
+    fn frame(&mut self, frame: &Frame) -> Result<()> {
+        let mut out = BufWriter::new(File::create(self.next_path())?);
+        let (width, height, pixels) = pixels(frame, self.scale);
+        match self.format {
+            ImageFormat::Ppm => {
+                write!(out, "P6\n{width} {height}\n255\n")?;
+                out.write_all(&pixels)?;
+            }
+            ImageFormat::Png => {
+                let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .write_header()
+                    .and_then(|mut writer| writer.write_image_data(&pixels))
+                    .map_err(io::Error::other)?;
+            }
+        }
+        out.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// The width and height of the image of `frame`, and its RGB pixels row by
+/// row.
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.cells.width() * scale, frame.cells.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in frame.cells.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| {
+                let Rgb(r, g, b) = cell.color;
+                [r, g, b].repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+    (width, height, pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Cell = Cell::new('.', Rgb(0, 0, 0));
+    const RED: Cell = Cell::new('#', Rgb(255, 0, 0));
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new("step 1", 2, 1, BLACK);
+        frame.draw((1, 0), RED);
+        frame.draw((5, 5), RED);
+        frame
+    }
+
+    struct Count(usize);
+
+    impl FrameSink for Count {
+        fn frame(&mut self, _: &Frame) -> Result<()> {
+            self.0 += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut count = Count(0);
+        let mut recorder = Recorder::new(&mut count, 3);
+        let mut rendered = 0;
+        for _ in 0..7 {
+            recorder.step(|| {
+                rendered += 1;
+                frame()
+            });
+        }
+        assert_eq!(recorder.finish().unwrap(), 3);
+        assert_eq!((rendered, count.0), (3, 3));
+    }
+
+    #[test]
+    fn test_animator() {
+        let mut out = vec![];
+        Animator::new(&mut out, 0.0).frame(&frame()).unwrap();
+        let screen = String::from_utf8(out).unwrap();
+        assert!(screen.starts_with("\x1b[?25l\x1b[H\x1b[2Jstep 1\n"));
+        assert!(screen.contains("\x1b[38;2;0;0;0m.\x1b[38;2;255;0;0m#\x1b[0m"));
+        assert!(screen.ends_with("\x1b[?25h"));
+        assert_eq!(frame().to_string(), ".#");
+    }
+
+    #[test]
+    fn test_pixels() {
+        let (width, height, pixels) = pixels(&frame(), 2);
+        assert_eq!((width, height), (4, 2));
+        let row = [0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0];
+        assert_eq!(pixels, [row, row].concat());
+    }
+}
@@ -0,0 +1,241 @@
+//! The two solvers of days 12, 15 and 19 against each other on small random
+//! inputs, and against naive implementations. A failing case is shrunk by
+//! proptest and recorded in
+//! `differential.proptest-regressions`, which is replayed first afterwards.
+
+use adventofcode::{day12, day15, day19, Grid};
+use proptest::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+// day 12: the breadth and depth first searches, and a relaxation of every
+// cell
+
+/// A small heightmap of `a` to `e`, so that some climbs are blocked, with a
+/// start and an end.
+fn heightmap() -> impl Strategy<Value = (day12::Map, day12::Position, day12::Position)> {
+    (1usize..=6, 1usize..=6)
+        .prop_flat_map(|(width, height)| {
+            (
+                prop::collection::vec(prop::collection::vec(1u32..=5, width), height),
+                (0..width, 0..height),
+                (0..width, 0..height),
+            )
+        })
+        .prop_map(|(rows, (start_x, start_y), (end_x, end_y))| {
+            (
+                Grid::from_rows(rows).unwrap(),
+                day12::Position::new(start_x, start_y),
+                day12::Position::new(end_x, end_y),
+            )
+        })
+}
+
+/// The fewest steps from any of `starts` to `end`, lowering the steps of the
+/// neighbours of every cell until nothing changes.
+fn fewest_steps(
+    map: &day12::Map,
+    starts: &[day12::Position],
+    end: day12::Position,
+) -> Option<usize> {
+    let mut steps = map.map(|_| None::<usize>);
+    for start in starts {
+        steps[*start] = Some(0);
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for position in map.positions() {
+            let Some(here) = steps[position] else {
+                continue;
+            };
+            for next in map.neighbours4(position) {
+                if map[next] <= map[position] + 1 && steps[next].is_none_or(|s| s > here + 1) {
+                    steps[next] = Some(here + 1);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    steps[end]
+}
+
+// day 15: the line scan of part 1 and `check_line` of part 2, and every
+// position of the line
+
+type Report = Vec<((i64, i64), (i64, i64))>;
+
+/// Up to 6 sensors and their beacons around the origin. A beacon is not always
+/// the closest one to its sensor, the solvers do not rely on it.
+fn report() -> impl Strategy<Value = Report> {
+    let position = || (-10i64..=10, -10i64..=10);
+    prop::collection::vec((position(), position()), 1..=6)
+}
+
+fn parse_report(report: &Report) -> Vec<day15::Pair> {
+    let input: String = report
+        .iter()
+        .map(|((sx, sy), (bx, by))| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+        })
+        .collect();
+    day15::parse_input(&input).unwrap()
+}
+
+fn is_covered(report: &Report, (x, y): (i64, i64)) -> bool {
+    report.iter().any(|((sx, sy), (bx, by))| {
+        (sx - x).abs() + (sy - y).abs() <= (sx - bx).abs() + (sy - by).abs()
+    })
+}
+
+// day 19: the pruned search and the recursive one, and trying every robot at
+// every minute
+
+/// The ore, clay, obsidian and geode robot costs, in ore, clay and obsidian.
+type Costs = [[u32; 3]; 4];
+
+fn costs() -> impl Strategy<Value = Costs> {
+    (
+        1u32..=4,
+        1u32..=4,
+        (1u32..=4, 1u32..=8),
+        (1u32..=4, 1u32..=8),
+    )
+        .prop_map(
+            |(ore, clay, (obsidian_ore, obsidian_clay), (geode_ore, geode_obsidian))| {
+                [
+                    [ore, 0, 0],
+                    [clay, 0, 0],
+                    [obsidian_ore, obsidian_clay, 0],
+                    [geode_ore, 0, geode_obsidian],
+                ]
+            },
+        )
+}
+
+fn parse_blueprint(costs: &Costs) -> Vec<day19::Blueprint> {
+    let input = format!(
+        "Blueprint 1: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+        costs[0][0], costs[1][0], costs[2][0], costs[2][1], costs[3][0], costs[3][2]
+    );
+    day19::parse_input(&input).unwrap()
+}
+
+/// The most geodes open after `minutes`, building any robot that can be paid
+/// for or none at every minute.
+fn most_geodes(costs: &Costs, minutes: u32) -> u32 {
+    type State = (u32, [u32; 4], [u32; 4]);
+
+    fn best(costs: &Costs, state: State, cache: &mut HashMap<State, u32>) -> u32 {
+        let (minutes, stock, robots) = state;
+        if minutes == 0 {
+            return stock[3];
+        }
+        if let Some(&geodes) = cache.get(&state) {
+            return geodes;
+        }
+
+        let mut geodes = 0;
+        for robot in (0..4).map(Some).chain([None]) {
+            let mut stock = stock;
+            let mut new_robots = robots;
+            if let Some(robot) = robot {
+                if (0..3).any(|rock| stock[rock] < costs[robot][rock]) {
+                    continue;
+                }
+                (0..3).for_each(|rock| stock[rock] -= costs[robot][rock]);
+                new_robots[robot] += 1;
+            }
+            (0..4).for_each(|rock| stock[rock] += robots[rock]);
+            geodes = geodes.max(best(costs, (minutes - 1, stock, new_robots), cache));
+        }
+
+        cache.insert(state, geodes);
+        geodes
+    }
+
+    best(costs, (minutes, [0; 4], [1, 0, 0, 0]), &mut HashMap::new())
+}
+
+proptest! {
+    #[test]
+    fn day12_part1((map, start, end) in heightmap()) {
+        let steps = day12::part1(&map, &start, &end).ok();
+        let backtrack = day12::part1_backtrack(&map, &start, &end);
+
+        // both find a path or none, the depth first one not always the
+        // shortest
+        prop_assert_eq!(backtrack.is_some(), steps.is_some());
+        if let (Some(backtrack), Some(steps)) = (backtrack, steps) {
+            prop_assert!(backtrack >= steps, "{} < {}", backtrack, steps);
+        }
+        prop_assert_eq!(steps, fewest_steps(&map, &[start], end));
+    }
+
+    #[test]
+    fn day12_part2((map, _, end) in heightmap()) {
+        let starts: Vec<day12::Position> = map
+            .iter()
+            .filter(|(_, height)| **height == 1)
+            .map(|((x, y), _)| day12::Position::new(x, y))
+            .collect();
+
+        let part2 = day12::part2(&map, &starts, &end).ok();
+        prop_assert_eq!(part2, fewest_steps(&map, &starts, end));
+    }
+
+    #[test]
+    fn day15_part1(report in report(), row in -15i64..=15) {
+        let pairs = parse_report(&report);
+        let beacons: HashSet<(i64, i64)> = report.iter().map(|(_, beacon)| *beacon).collect();
+        // no sensor reaches further than 50 from the origin, `check_line`
+        // tells whether each position is covered
+        let positions = (-60..=60).filter(|x| !beacons.contains(&(*x, row)));
+        let checked = positions
+            .clone()
+            .filter(|x| {
+                let limits = day15::Limits { x_min: *x, x_max: *x, y_min: row, y_max: row };
+                day15::check_line(&pairs, &limits, row).is_none()
+            })
+            .count();
+        let covered = positions.filter(|x| is_covered(&report, (*x, row))).count();
+
+        let scanned = day15::part1(&pairs, row);
+        prop_assert_eq!(scanned, checked);
+        prop_assert_eq!(scanned, covered);
+    }
+
+    #[test]
+    fn day15_check_line(
+        report in report(),
+        row in -15i64..=15,
+        x_min in -15i64..=0,
+        x_max in 0i64..=15,
+    ) {
+        let limits = day15::Limits { x_min, x_max, y_min: row, y_max: row };
+        let uncovered = (x_min..=x_max).find(|x| !is_covered(&report, (*x, row)));
+
+        let found = day15::check_line(&parse_report(&report), &limits, row);
+        prop_assert_eq!(found, uncovered.map(|x| day15::Position::new(x, row)));
+    }
+
+    #[test]
+    fn day15_part2(report in report(), search_max in 0i64..=12) {
+        let limits = day15::Limits { x_min: 0, x_max: search_max, y_min: 0, y_max: search_max };
+        let uncovered = (0..=search_max)
+            .flat_map(|y| (0..=search_max).map(move |x| (x, y)))
+            .find(|position| !is_covered(&report, *position));
+
+        let frequency = day15::part2(&parse_report(&report), &limits);
+        prop_assert_eq!(frequency, uncovered.map(|(x, y)| x * 4000000 + y));
+    }
+
+    #[test]
+    fn day19_blueprint(costs in costs(), minutes in 0u32..=12) {
+        let blueprints = parse_blueprint(&costs);
+        let geodes = day19::run_blueprint(&blueprints[0], minutes);
+        prop_assert_eq!(geodes, day19::run_blueprint_recursive(&blueprints[0], minutes));
+        prop_assert_eq!(geodes, most_geodes(&costs, minutes));
+    }
+}
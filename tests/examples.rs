@@ -0,0 +1,23 @@
+//! The examples of `fixtures/`, one test per day, example and part with a
+//! recorded answer. The tests are generated by the build script, so a new
+//! example only needs its `.txt` and `.toml` files.
+
+use adventofcode::{get_day, Check, Fixture, Part};
+
+fn check_fixture(day: u8, name: &str, part: Part) {
+    let fixture = Fixture::new(day, name);
+    let answers = fixture.answers().unwrap();
+    let input = get_day(day)
+        .unwrap()
+        .parse_with(&fixture.input().unwrap(), &answers.options)
+        .unwrap();
+    let answer = input.solve(part).unwrap();
+
+    match answers.check(part, &answer) {
+        Check::Pass => {}
+        Check::Fail { expected } => panic!("expected {expected}, got {answer}"),
+        Check::Missing => panic!("no answer recorded for {part}"),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
@@ -0,0 +1,240 @@
+//! The solvers used as a library.
+
+use adventofcode::{
+    blocks, day13, day15, day16, day17, day20, day21, day25, get_day, lines, Answer, Config,
+    Direction, Error, Fixture, Frame, FrameSink, Grid, Line, Part, Pattern, Recorder, Result,
+};
+use std::cmp::Ordering;
+
+#[test]
+fn test_compare_packets() {
+    let packets = day13::parse_input_part2("[[1],[2,3,4]]\n[[1],4]\n[9]\n[[8,7,6]]\n").unwrap();
+    assert_eq!(
+        day13::compare_packets(&packets[0], &packets[1]),
+        Ordering::Less
+    );
+    assert_eq!(
+        day13::compare_packets(&packets[2], &packets[3]),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_merge_ranges() {
+    let merged = day15::MyRange(1..=4).merge(&day15::MyRange(3..=8)).unwrap();
+    assert_eq!(merged.0, 1..=8);
+    assert!(day15::MyRange(1..=2)
+        .merge(&day15::MyRange(4..=5))
+        .is_none());
+}
+
+#[test]
+fn test_snafu() {
+    let numbers = day25::parse_input("1=-0-2\n12111\n").unwrap();
+    assert_eq!(numbers[0].to_number().unwrap(), 1747);
+    assert_eq!(day25::Snafu::to_snafu(2022).to_string(), "1=11-2");
+    assert_eq!(day25::part1(&numbers).unwrap().to_string(), "1-111=");
+
+    // zero and the negative sums
+    for (input, sum) in [("0\n", "0"), ("=\n1\n", "-"), ("=\n", "=")] {
+        let numbers = day25::parse_input(input).unwrap();
+        assert_eq!(day25::part1(&numbers).unwrap().to_string(), sum);
+    }
+    for number in -200..=200 {
+        let snafu = day25::Snafu::to_snafu(number);
+        let parsed = day25::parse_input(&snafu.to_string()).unwrap();
+        assert_eq!(parsed[0].to_number().unwrap(), number, "{snafu}");
+    }
+    assert_eq!(day25::Snafu::to_snafu(i64::MIN).to_string().len(), 28);
+}
+
+#[test]
+fn test_mix_one_number() {
+    let numbers = day20::parse_input("0\n").unwrap();
+    assert_eq!(day20::part1(&numbers).unwrap(), 0);
+    assert_eq!(day20::part2(&numbers, 10).unwrap(), 0);
+}
+
+#[test]
+fn test_humn() {
+    // `humn` out of root's operations, or on both sides of one, the
+    // example with `humn` on the right of root being example2
+    for input in [
+        "root: aaaa + bbbb\naaaa: 3\nbbbb: 5\nhumn: 5\n",
+        "root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 5\nhumn: 5\n",
+        "root: aaaa + humn\naaaa: humn - bbbb\nbbbb: 5\nhumn: 5\n",
+    ] {
+        let jobs = day21::parse_input(input).unwrap();
+        assert!(
+            matches!(day21::part2(&jobs), Err(Error::NoSolution(_))),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_unreachable_valve() {
+    // no tunnel leads to CC
+    let valves = day16::parse_input(
+        "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=5; tunnel leads to valve AA
+Valve CC has flow rate=3; tunnel leads to valve AA
+",
+    )
+    .unwrap();
+    assert!(matches!(
+        day16::part1(&valves, 30),
+        Err(Error::NoSolution(_))
+    ));
+    assert!(matches!(
+        day16::part2(&valves, 26),
+        Err(Error::NoSolution(_))
+    ));
+}
+
+#[test]
+fn test_overflow() {
+    let numbers = day25::parse_input(&"2".repeat(30)).unwrap();
+    assert!(matches!(numbers[0].to_number(), Err(Error::Overflow(_))));
+
+    let jobs = day21::parse_input("root: aaaa * aaaa\naaaa: 9999999999\nhumn: 5\n").unwrap();
+    assert!(matches!(day21::part1(&jobs), Err(Error::Overflow(_))));
+    let jobs = day21::parse_input("root: aaaa - bbbb\naaaa: 3\nbbbb: 5\nhumn: 5\n").unwrap();
+    assert_eq!(day21::part1(&jobs).unwrap(), -2);
+
+    let numbers = day20::parse_input("0\n12345678912345\n").unwrap();
+    assert!(matches!(
+        day20::decrypt(&numbers, 811589153),
+        Err(Error::Overflow(_))
+    ));
+}
+
+#[test]
+fn test_parse() {
+    let input = "Sensor at x=2, y=-18\nSensor at x=9, y=x\n";
+    let pattern = Pattern::new("Sensor at x={x}, y={y}");
+    let sensors: Vec<Line> = lines(input).collect();
+    let fields = pattern.matches(&sensors[0]).unwrap();
+    assert_eq!(
+        (fields.get("x").unwrap(), fields.get("y").unwrap()),
+        (2, -18)
+    );
+    let error = pattern.matches(&sensors[1]).unwrap().get::<i64>("y");
+    assert!(matches!(error, Err(Error::InvalidValue { .. })));
+
+    let maps = blocks("#.\n.#\n\n##\n");
+    let grid = Grid::from_lines(&maps[1], None, |_, c| Some(c), "`#` or `.`").unwrap();
+    assert_eq!(grid[(1, 0)], '#');
+}
+
+#[test]
+fn test_tower() {
+    use day17::{Position, Rock, Shape, Tower};
+
+    let jets = day17::parse_input(">>><<><>><<<>\n").unwrap();
+    let mut tower = Tower::new(&jets).unwrap();
+    for _ in 0..3 {
+        tower.drop_rock(|_, _| {});
+    }
+    assert_eq!((tower.rocks(), tower.height()), (3, 6));
+    assert_eq!(day17::run(&jets, 3).unwrap(), 6);
+    assert!(Tower::new(&[]).is_none());
+    assert!(matches!(day17::run(&[], 3), Err(Error::NoSolution(_))));
+
+    // the vertical line against the left wall, then on the floor
+    let shape = Shape::new(Rock::VerticalLine, 1);
+    let occupied = (0..7).map(|x| Position(x, 0)).collect();
+    let moved = shape.moved(Direction::Left, &occupied).unwrap();
+    assert_eq!(moved.positions()[0], Position(1, 1));
+    let moved = moved.moved(Direction::Left, &occupied).unwrap();
+    assert!(moved.moved(Direction::Left, &occupied).is_none());
+    assert!(moved.moved(Direction::Down, &occupied).is_none());
+}
+
+struct Frames(Vec<Frame>);
+
+impl FrameSink for Frames {
+    fn frame(&mut self, frame: &Frame) -> Result<()> {
+        self.0.push(frame.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_visualize() {
+    for day in [14, 17, 22, 23, 24] {
+        let fixture = Fixture::new(day, "example1");
+        let options = fixture.answers().unwrap().options;
+        let input = get_day(day)
+            .unwrap()
+            .parse_with(&fixture.input().unwrap(), &options)
+            .unwrap();
+
+        for part in [Part::One, Part::Two] {
+            let mut frames = Frames(vec![]);
+            let mut recorder = Recorder::new(&mut frames, 1);
+            let answer = input.visualize(part, &mut recorder).unwrap().unwrap();
+            let drawn = recorder.finish().unwrap();
+
+            assert_eq!(answer, input.solve(part).unwrap(), "day {day} {part}");
+            assert_eq!(drawn, frames.0.len());
+            assert!(drawn > 1, "day {day} {part}");
+        }
+    }
+
+    // a rock far to the left of the sand, outside of the floor of part 2
+    let input = get_day(14)
+        .unwrap()
+        .parse("100,5 -> 102,5\n498,10 -> 502,10\n")
+        .unwrap();
+    for part in [Part::One, Part::Two] {
+        let mut frames = Frames(vec![]);
+        let mut recorder = Recorder::new(&mut frames, 1);
+        let answer = input.visualize(part, &mut recorder).unwrap().unwrap();
+        recorder.finish().unwrap();
+        assert_eq!(answer, input.solve(part).unwrap());
+        // the frame starts at the rock
+        assert_eq!(frames.0.last().unwrap().cells[(0, 5)].glyph, '#');
+    }
+
+    let input = get_day(1).unwrap().parse("1\n\n2\n").unwrap();
+    let mut frames = Frames(vec![]);
+    assert!(input
+        .visualize(Part::One, &mut Recorder::new(&mut frames, 1))
+        .is_none());
+}
+
+#[test]
+fn test_shared_options() {
+    let mut config = Config::default();
+    config.set("part1_minutes=10").unwrap();
+
+    // only days 16 and 19 have the option, the others run as usual
+    let days = 15..=19;
+    let names: Vec<&str> = days
+        .clone()
+        .flat_map(|day| get_day(day).unwrap().option_names().iter().copied())
+        .collect();
+    config.check_shared(&names).unwrap();
+    for number in days {
+        let day = get_day(number).unwrap();
+        let fixture = Fixture::new(number, "example1");
+        let mut options = fixture.answers().unwrap().options;
+        options.merge(&config.day(number, day.option_names()));
+        let input = day
+            .parse_with(&fixture.input().unwrap(), &options)
+            .unwrap_or_else(|e| panic!("day {number}: {e}"));
+        if number == 19 {
+            // no geode can be open in 10 minutes
+            assert_eq!(input.solve(Part::One).unwrap(), Answer::from(0u32));
+        }
+    }
+
+    let names: Vec<&str> = (1..=3)
+        .flat_map(|day| get_day(day).unwrap().option_names().iter().copied())
+        .collect();
+    assert!(matches!(
+        config.check_shared(&names),
+        Err(Error::InvalidOption(_))
+    ));
+}